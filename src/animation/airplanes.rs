@@ -1,3 +1,4 @@
+use crate::animation::{FrameContext, Layer, ParticleSystem};
 use crate::render::TerminalRenderer;
 use crossterm::style::Color;
 use rand::prelude::*;
@@ -15,19 +16,41 @@ pub struct AirplaneSystem {
     terminal_width: u16,
     terminal_height: u16,
     spawn_cooldown: u16,
+    density: f32,
 }
 
 impl AirplaneSystem {
-    pub fn new(terminal_width: u16, terminal_height: u16) -> Self {
+    pub fn new(terminal_width: u16, terminal_height: u16, density: f32) -> Self {
         Self {
             planes: Vec::with_capacity(2),
             terminal_width,
             terminal_height,
             spawn_cooldown: 0,
+            density,
         }
     }
 
-    pub fn update(&mut self, terminal_width: u16, terminal_height: u16, rng: &mut impl Rng) {
+    fn spawn_plane(&mut self, rng: &mut impl Rng) {
+        let y = (rng.random::<u16>() % (self.terminal_height / 4)) as f32;
+        let speed = 0.3 + (rng.random::<f32>() * 0.2);
+
+        self.planes.push(Airplane { x: 0.0, y, speed });
+    }
+}
+
+impl ParticleSystem for AirplaneSystem {
+    fn layer(&self) -> Layer {
+        Layer::Atmosphere
+    }
+
+    fn is_active(&self, ctx: &FrameContext) -> bool {
+        !ctx.conditions.is_precipitating() && !ctx.conditions.is_foggy
+    }
+
+    fn update(&mut self, ctx: &mut FrameContext) {
+        let (terminal_width, terminal_height) = (ctx.width, ctx.height);
+        let rng = &mut ctx.rng;
+
         self.terminal_width = terminal_width;
         self.terminal_height = terminal_height;
 
@@ -38,20 +61,13 @@ impl AirplaneSystem {
         self.planes.retain(|p| p.x < terminal_width as f32);
 
         self.spawn_cooldown = self.spawn_cooldown.saturating_sub(1);
-        if self.spawn_cooldown == 0 && rng.random::<f32>() < 0.001 {
+        if self.spawn_cooldown == 0 && rng.random::<f32>() < 0.001 * self.density {
             self.spawn_plane(rng);
             self.spawn_cooldown = 600 + (rng.random::<u16>() % 300);
         }
     }
 
-    fn spawn_plane(&mut self, rng: &mut impl Rng) {
-        let y = (rng.random::<u16>() % (self.terminal_height / 4)) as f32;
-        let speed = 0.3 + (rng.random::<f32>() * 0.2);
-
-        self.planes.push(Airplane { x: 0.0, y, speed });
-    }
-
-    pub fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
+    fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
         let airplane_art = [
             "           _",
             "         -=\\`\\",
@@ -1,8 +1,12 @@
+use crate::animation::{FrameContext, Layer, ParticleSystem, scaled};
 use crate::render::TerminalRenderer;
 use crossterm::style::Color;
 use rand::prelude::*;
 use std::io;
 
+/// Wind speed in km/h above which birds stay grounded.
+const GROUNDING_WIND_SPEED: f32 = 40.0;
+
 struct Bird {
     x: f32,
     y: f32,
@@ -16,23 +20,57 @@ pub struct BirdSystem {
     birds: Vec<Bird>,
     terminal_width: u16,
     terminal_height: u16,
+    wind_x: f32,
+    grounded: bool,
+    max_birds: usize,
 }
 
 impl BirdSystem {
-    pub fn new(terminal_width: u16, terminal_height: u16) -> Self {
+    pub fn new(terminal_width: u16, terminal_height: u16, density: f32) -> Self {
         Self {
             birds: Vec::with_capacity(3),
             terminal_width,
             terminal_height,
+            wind_x: 0.0,
+            grounded: false,
+            max_birds: scaled(3, density),
         }
     }
 
-    pub fn update(&mut self, terminal_width: u16, terminal_height: u16, rng: &mut impl Rng) {
+    pub fn set_wind(&mut self, speed_kmh: f32, direction_deg: f32) {
+        let speed_factor = speed_kmh / 80.0;
+        let direction_rad = direction_deg.to_radians();
+        let x_component = -direction_rad.sin();
+        self.wind_x = speed_factor * x_component;
+        self.grounded = speed_kmh >= GROUNDING_WIND_SPEED;
+    }
+
+    #[cfg(test)]
+    pub fn is_grounded(&self) -> bool {
+        self.grounded
+    }
+}
+
+impl ParticleSystem for BirdSystem {
+    fn layer(&self) -> Layer {
+        Layer::Atmosphere
+    }
+
+    fn is_active(&self, ctx: &FrameContext) -> bool {
+        ctx.conditions.is_day && !ctx.conditions.is_precipitating()
+    }
+
+    fn update(&mut self, ctx: &mut FrameContext) {
+        self.set_wind(ctx.wind.speed_kmh, ctx.wind.direction_deg);
+        let (terminal_width, terminal_height) = (ctx.width, ctx.height);
+        let rng = &mut ctx.rng;
+
         self.terminal_width = terminal_width;
         self.terminal_height = terminal_height;
 
         for bird in &mut self.birds {
-            bird.x += bird.speed;
+            // Headwind slows birds down but never pushes them backwards
+            bird.x += (bird.speed + self.wind_x).max(0.05);
             bird.flap_timer += 1;
             if bird.flap_timer > 5 {
                 bird.flap_state = !bird.flap_state;
@@ -42,7 +80,7 @@ impl BirdSystem {
         }
 
         self.birds.retain(|b| b.x < terminal_width as f32);
-        if self.birds.len() < 3 && rng.random::<f32>() < 0.01 {
+        if !self.grounded && self.birds.len() < self.max_birds && rng.random::<f32>() < 0.01 {
             let y = (rng.random::<u16>() % (terminal_height / 3)) as f32;
             let speed = 0.2 + (rng.random::<f32>() * 0.2);
             self.birds.push(Bird {
@@ -56,7 +94,7 @@ impl BirdSystem {
         }
     }
 
-    pub fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
+    fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
         for bird in &self.birds {
             let x = bird.x as u16;
             let y = bird.y as u16;
@@ -1,3 +1,4 @@
+use crate::animation::{FrameContext, Layer, ParticleSystem};
 use crate::render::TerminalRenderer;
 use crossterm::style::Color;
 use rand::prelude::*;
@@ -27,10 +28,12 @@ impl SmokeParticle {
         }
     }
 
-    fn update(&mut self) {
+    fn update(&mut self, wind_x: f32) {
         self.age += 1;
-        self.y -= 0.2;
-        self.x += self.drift;
+        // Strong wind flattens the plume and shears it more the higher it rises
+        let life_ratio = self.age as f32 / self.max_age as f32;
+        self.y -= 0.2 * (1.0 - wind_x.abs().min(1.0) * 0.6);
+        self.x += self.drift + wind_x * (0.3 + life_ratio);
     }
 
     fn is_alive(&self) -> bool {
@@ -53,33 +56,61 @@ pub struct ChimneySmoke {
     particles: Vec<SmokeParticle>,
     spawn_counter: u32,
     spawn_rate: u32,
+    wind_x: f32,
 }
 
 impl ChimneySmoke {
-    pub fn new() -> Self {
+    pub fn new(density: f32) -> Self {
         Self {
             particles: Vec::with_capacity(MAX_PARTICLES),
             spawn_counter: 0,
-            spawn_rate: 8,
+            // Puffs every 8 frames at the default density
+            spawn_rate: (8.0 / density).round().max(1.0) as u32,
+            wind_x: 0.0,
         }
     }
 
-    pub fn update(&mut self, chimney_x: u16, chimney_y: u16, rng: &mut impl Rng) {
+    pub fn set_wind(&mut self, speed_kmh: f32, direction_deg: f32) {
+        let speed_factor = speed_kmh / 60.0;
+        let direction_rad = direction_deg.to_radians();
+        let x_component = -direction_rad.sin();
+        self.wind_x = speed_factor * x_component;
+    }
+}
+
+impl ParticleSystem for ChimneySmoke {
+    fn layer(&self) -> Layer {
+        Layer::Smoke
+    }
+
+    fn is_active(&self, ctx: &FrameContext) -> bool {
+        !ctx.conditions.is_raining && !ctx.conditions.is_thunderstorm
+    }
+
+    /// Advances the plume and releases a puff from every emitter.
+    fn update(&mut self, ctx: &mut FrameContext) {
+        self.set_wind(ctx.wind.gust_speed_kmh, ctx.wind.direction_deg);
+        let emitters = ctx.emitters;
+        let rng = &mut ctx.rng;
+
         for particle in &mut self.particles {
-            particle.update();
+            particle.update(self.wind_x);
         }
 
         self.particles.retain(|p| p.is_alive() && p.y >= 0.0);
 
         self.spawn_counter += 1;
-        if self.spawn_counter >= self.spawn_rate && self.particles.len() < MAX_PARTICLES {
+        if self.spawn_counter >= self.spawn_rate {
             self.spawn_counter = 0;
-            self.particles
-                .push(SmokeParticle::new(chimney_x, chimney_y, rng));
+            for &(x, y) in emitters {
+                if self.particles.len() < MAX_PARTICLES {
+                    self.particles.push(SmokeParticle::new(x, y, rng));
+                }
+            }
         }
     }
 
-    pub fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
+    fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
         for particle in &self.particles {
             let x = particle.x as i16;
             let y = particle.y as i16;
@@ -101,6 +132,6 @@ impl ChimneySmoke {
 
 impl Default for ChimneySmoke {
     fn default() -> Self {
-        Self::new()
+        Self::new(1.0)
     }
 }
@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+/// Rate the particle systems were tuned for. Every update advances the
+/// simulation by exactly one step of this length, whatever the frame rate:
+/// systems move by fixed amounts per step, so animation speed is tied to
+/// this rate and frames drawn faster than it show the same state again.
+pub const SIMULATION_HZ: u32 = 30;
+pub const SIMULATION_STEP: Duration = Duration::from_nanos(1_000_000_000 / SIMULATION_HZ as u64);
+
+/// Longest stretch of wall time simulated in one go. This covers the slowest
+/// frame rate, but after a stall such as a suspended process the animation
+/// resumes instead of fast-forwarding.
+const MAX_FRAME_TIME: Duration = Duration::from_secs(1);
+
+/// Turns elapsed wall time into a whole number of fixed simulation steps,
+/// carrying the remainder over to the next frame.
+#[derive(Debug, Default)]
+pub struct FixedTimestep {
+    accumulator: Duration,
+}
+
+impl FixedTimestep {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the time since the last frame and returns how many steps are due.
+    pub fn advance(&mut self, elapsed: Duration) -> u32 {
+        self.accumulator += elapsed.min(MAX_FRAME_TIME);
+
+        let mut steps = 0;
+        while self.accumulator >= SIMULATION_STEP {
+            self.accumulator -= SIMULATION_STEP;
+            steps += 1;
+        }
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_do_not_depend_on_frame_rate() {
+        let second = SIMULATION_STEP * SIMULATION_HZ;
+
+        for fps in [1, 10, 24, 30, 60, 144] {
+            let mut clock = FixedTimestep::new();
+            let frame = second / fps;
+            let steps: u32 = (0..fps * 4).map(|_| clock.advance(frame)).sum();
+
+            assert!(
+                steps.abs_diff(SIMULATION_HZ * 4) <= 1,
+                "{fps} fps ran {steps} steps"
+            );
+        }
+    }
+
+    #[test]
+    fn test_remainder_carries_over() {
+        let mut clock = FixedTimestep::new();
+        let half = SIMULATION_STEP / 2;
+
+        assert_eq!(clock.advance(half), 0);
+        assert_eq!(clock.advance(SIMULATION_STEP - half), 1);
+        assert_eq!(clock.advance(Duration::ZERO), 0);
+    }
+
+    #[test]
+    fn test_long_stall_is_capped() {
+        let mut clock = FixedTimestep::new();
+        let max_steps = (MAX_FRAME_TIME.as_nanos() / SIMULATION_STEP.as_nanos()) as u32;
+
+        assert_eq!(clock.advance(Duration::from_secs(3600)), max_steps);
+    }
+}
@@ -1,4 +1,6 @@
+use crate::animation::{FrameContext, Layer, ParticleSystem, scaled};
 use crate::render::TerminalRenderer;
+use crate::weather::WeatherCondition;
 use crossterm::style::Color;
 use rand::prelude::*;
 use std::io;
@@ -18,6 +20,8 @@ pub struct CloudSystem {
     clouds: Vec<Cloud>,
     terminal_width: u16,
     terminal_height: u16,
+    drift: f32,
+    density: f32,
 }
 
 impl CloudSystem {
@@ -32,13 +36,26 @@ impl CloudSystem {
             cloud.color = color;
         }
     }
+
+    /// Derives cloud drift from the wind. Clouds move with the wind, faster
+    /// in stronger wind, and keep a slow drift even when it is calm.
+    pub fn set_wind(&mut self, speed_kmh: f32, direction_deg: f32) {
+        let direction_rad = direction_deg.to_radians();
+        let x_component = -direction_rad.sin();
+        let magnitude = (speed_kmh / 15.0).clamp(0.3, 4.0);
+        self.drift = if x_component < 0.0 {
+            -magnitude
+        } else {
+            magnitude
+        };
+    }
 }
 
 impl CloudSystem {
-    pub fn new(terminal_width: u16, terminal_height: u16) -> Self {
+    pub fn new(terminal_width: u16, terminal_height: u16, density: f32) -> Self {
         let mut rng = rand::rng();
         // Add a few initial clouds
-        let count = std::cmp::max(1, terminal_width / 20);
+        let count = scaled(std::cmp::max(1, terminal_width / 20) as usize, density);
 
         let max_capacity = (terminal_width / 20) as usize;
         let mut clouds = Vec::with_capacity(max_capacity);
@@ -48,6 +65,7 @@ impl CloudSystem {
                 terminal_width,
                 terminal_height,
                 true,
+                false,
                 Color::White,
                 &mut rng,
             ));
@@ -57,6 +75,8 @@ impl CloudSystem {
             clouds,
             terminal_width,
             terminal_height,
+            drift: 1.0,
+            density,
         }
     }
 
@@ -64,6 +84,7 @@ impl CloudSystem {
         width: u16,
         height: u16,
         random_x: bool,
+        from_right: bool,
         color: Color,
         rng: &mut impl Rng,
     ) -> Cloud {
@@ -77,6 +98,8 @@ impl CloudSystem {
 
         let x = if random_x {
             (rng.random::<u16>() % width) as f32
+        } else if from_right {
+            width as f32
         } else {
             -(shape[0].len() as f32)
         };
@@ -120,29 +143,53 @@ impl CloudSystem {
 
         shapes.to_vec()
     }
+}
+
+impl ParticleSystem for CloudSystem {
+    fn layer(&self) -> Layer {
+        Layer::Atmosphere
+    }
+
+    fn is_active(&self, ctx: &FrameContext) -> bool {
+        let is_clear = ctx
+            .weather
+            .is_some_and(|weather| weather.condition == WeatherCondition::Clear);
+        ctx.conditions.is_cloudy || (is_clear && !ctx.conditions.is_precipitating())
+    }
+
+    fn update(&mut self, ctx: &mut FrameContext) {
+        let condition = ctx.weather.map(|weather| weather.condition);
+        let is_clear = condition == Some(WeatherCondition::Clear);
+        let cloud_color = match condition {
+            Some(WeatherCondition::Clear) => Color::White,
+            Some(WeatherCondition::PartlyCloudy) => Color::Grey,
+            _ => Color::DarkGrey,
+        };
+        self.set_cloud_color(is_clear);
+        self.set_wind(ctx.wind.speed_kmh, ctx.wind.direction_deg);
+        let (terminal_width, terminal_height) = (ctx.width, ctx.height);
+        let rng = &mut ctx.rng;
 
-    pub fn update(
-        &mut self,
-        terminal_width: u16,
-        terminal_height: u16,
-        is_clear: bool,
-        cloud_color: Color,
-        rng: &mut impl Rng,
-    ) {
         self.terminal_width = terminal_width;
         self.terminal_height = terminal_height;
 
         for cloud in &mut self.clouds {
-            cloud.x += cloud.speed;
+            cloud.x += cloud.speed * self.drift;
         }
 
-        self.clouds.retain(|c| c.x < terminal_width as f32);
+        self.clouds.retain(|c| {
+            let cloud_width = c.shape.iter().map(|l| l.len()).max().unwrap_or(0) as f32;
+            c.x < terminal_width as f32 && c.x > -cloud_width - 1.0
+        });
 
-        let max_clouds = if is_clear {
-            (terminal_width / 40) as usize
-        } else {
-            (terminal_width / 20) as usize
-        };
+        let max_clouds = scaled(
+            if is_clear {
+                (terminal_width / 40) as usize
+            } else {
+                (terminal_width / 20) as usize
+            },
+            self.density,
+        );
 
         let spawn_chance = if is_clear { 0.002 } else { 0.005 };
 
@@ -151,23 +198,27 @@ impl CloudSystem {
                 terminal_width,
                 terminal_height,
                 false,
+                self.drift < 0.0,
                 cloud_color,
                 rng,
             ));
         }
     }
 
-    pub fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
+    fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
         for cloud in &self.clouds {
             for (i, line) in cloud.shape.iter().enumerate() {
                 let y = cloud.y as i16 + i as i16;
                 let x = cloud.x as i16;
 
                 if y >= 0 && y < self.terminal_height as i16 {
+                    // Clip the part of the cloud that has drifted off the left edge
+                    let skip = (-x).max(0) as usize;
+                    let visible: String = line.chars().skip(skip).collect();
                     renderer.render_line_colored(
                         std::cmp::max(0, x) as u16,
                         y as u16,
-                        line,
+                        &visible,
                         cloud.color,
                     )?;
                 }
@@ -1,4 +1,6 @@
+use crate::animation::{FrameContext, Layer, ParticleSystem, scaled};
 use crate::render::TerminalRenderer;
+use crate::weather::WeatherCondition;
 use crossterm::style::Color;
 use rand::prelude::*;
 use std::io;
@@ -113,26 +115,46 @@ pub struct FireflySystem {
     fireflies: Vec<Firefly>,
     terminal_width: u16,
     terminal_height: u16,
+    density: f32,
 }
 
 impl FireflySystem {
-    pub fn new(terminal_width: u16, terminal_height: u16) -> Self {
+    pub fn new(terminal_width: u16, terminal_height: u16, density: f32) -> Self {
         let fireflies_capacity = std::cmp::max(3, terminal_width / 15) as usize;
 
         Self {
             fireflies: Vec::with_capacity(fireflies_capacity),
             terminal_width,
             terminal_height,
+            density,
         }
     }
+}
+
+impl ParticleSystem for FireflySystem {
+    fn layer(&self) -> Layer {
+        Layer::Atmosphere
+    }
+
+    fn is_active(&self, ctx: &FrameContext) -> bool {
+        if ctx.conditions.is_day || ctx.conditions.is_precipitating() {
+            return false;
+        }
+
+        // Only on warm, clear nights
+        ctx.weather.is_some_and(|weather| {
+            weather.temperature > 15.0
+                && matches!(
+                    weather.condition,
+                    WeatherCondition::Clear | WeatherCondition::PartlyCloudy
+                )
+        })
+    }
+
+    fn update(&mut self, ctx: &mut FrameContext) {
+        let (terminal_width, terminal_height, horizon_y) = (ctx.width, ctx.height, ctx.horizon_y);
+        let rng = &mut ctx.rng;
 
-    pub fn update(
-        &mut self,
-        terminal_width: u16,
-        terminal_height: u16,
-        horizon_y: u16,
-        rng: &mut impl Rng,
-    ) {
         self.terminal_width = terminal_width;
         self.terminal_height = terminal_height;
 
@@ -140,14 +162,14 @@ impl FireflySystem {
             firefly.update(terminal_width, horizon_y, rng);
         }
 
-        let target_count = std::cmp::max(3, terminal_width / 15) as usize;
+        let target_count = scaled(std::cmp::max(3, terminal_width / 15) as usize, self.density);
         if self.fireflies.len() < target_count && rng.random::<f32>() < 0.01 {
             self.fireflies
                 .push(Firefly::new(terminal_width, horizon_y, rng));
         }
     }
 
-    pub fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
+    fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
         for firefly in &self.fireflies {
             if firefly.is_visible() {
                 let x = firefly.x as i16;
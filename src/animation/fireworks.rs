@@ -0,0 +1,171 @@
+use crate::animation::{FrameContext, Layer, ParticleSystem};
+use crate::render::TerminalRenderer;
+use crate::scene::events::Holiday;
+use crossterm::style::Color;
+use rand::prelude::*;
+use std::io;
+
+const BURST_LIFETIME: u8 = 14;
+const SPARKS_PER_BURST: usize = 12;
+const MAX_ROCKETS: usize = 3;
+
+const COLORS: [Color; 6] = [
+    Color::Red,
+    Color::Yellow,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Green,
+    Color::White,
+];
+
+struct Rocket {
+    x: f32,
+    y: f32,
+    burst_y: f32,
+    color: Color,
+}
+
+struct Spark {
+    x: f32,
+    y: f32,
+    vx: f32,
+    vy: f32,
+    age: u8,
+    color: Color,
+}
+
+impl Spark {
+    fn get_character(&self) -> char {
+        match self.age {
+            0..=4 => '*',
+            5..=9 => '+',
+            _ => '.',
+        }
+    }
+}
+
+/// Rockets launched from the horizon that burst into coloured sparks.
+pub struct FireworkSystem {
+    rockets: Vec<Rocket>,
+    sparks: Vec<Spark>,
+    terminal_width: u16,
+    terminal_height: u16,
+    density: f32,
+}
+
+impl FireworkSystem {
+    pub fn new(terminal_width: u16, terminal_height: u16, density: f32) -> Self {
+        Self {
+            rockets: Vec::with_capacity(MAX_ROCKETS),
+            sparks: Vec::with_capacity(MAX_ROCKETS * SPARKS_PER_BURST),
+            terminal_width,
+            terminal_height,
+            density,
+        }
+    }
+
+    fn render_point(
+        &self,
+        renderer: &mut TerminalRenderer,
+        x: f32,
+        y: f32,
+        ch: char,
+        color: Color,
+    ) -> io::Result<()> {
+        if x >= 0.0
+            && y >= 0.0
+            && (x as u16) < self.terminal_width
+            && (y as u16) < self.terminal_height
+        {
+            renderer.render_char(x as u16, y as u16, ch, color)?;
+        }
+        Ok(())
+    }
+}
+
+impl ParticleSystem for FireworkSystem {
+    fn layer(&self) -> Layer {
+        Layer::Atmosphere
+    }
+
+    fn is_active(&self, ctx: &FrameContext) -> bool {
+        !ctx.conditions.is_day && ctx.holidays.contains(&Holiday::NewYear)
+    }
+
+    fn update(&mut self, ctx: &mut FrameContext) {
+        let (terminal_width, terminal_height, horizon_y) = (ctx.width, ctx.height, ctx.horizon_y);
+        let rng = &mut ctx.rng;
+
+        self.terminal_width = terminal_width;
+        self.terminal_height = terminal_height;
+
+        let mut bursts = Vec::new();
+        self.rockets.retain_mut(|rocket| {
+            rocket.y -= 0.8;
+            if rocket.y <= rocket.burst_y {
+                bursts.push((rocket.x, rocket.y, rocket.color));
+                false
+            } else {
+                true
+            }
+        });
+
+        for (x, y, color) in bursts {
+            for i in 0..SPARKS_PER_BURST {
+                let angle = i as f32 / SPARKS_PER_BURST as f32 * std::f32::consts::TAU;
+                let speed = 0.6 + rng.random::<f32>() * 0.4;
+                self.sparks.push(Spark {
+                    x,
+                    y,
+                    // Terminal cells are about twice as tall as they are wide
+                    vx: angle.cos() * speed * 2.0,
+                    vy: angle.sin() * speed,
+                    age: 0,
+                    color,
+                });
+            }
+        }
+
+        self.sparks.retain_mut(|spark| {
+            spark.x += spark.vx;
+            spark.y += spark.vy;
+            spark.vx *= 0.85;
+            spark.vy = spark.vy * 0.85 + 0.05;
+            spark.age += 1;
+            spark.age < BURST_LIFETIME
+        });
+
+        let sky_height = horizon_y.saturating_sub(4);
+        if self.rockets.len() < MAX_ROCKETS
+            && sky_height > 4
+            && rng.random::<f32>() < 0.04 * self.density
+        {
+            let x = terminal_width as f32 * (0.1 + rng.random::<f32>() * 0.8);
+            let burst_y = 2.0 + rng.random::<f32>() * (sky_height as f32 / 2.0);
+            self.rockets.push(Rocket {
+                x,
+                y: horizon_y as f32,
+                burst_y,
+                color: COLORS[rng.random_range(0..COLORS.len())],
+            });
+        }
+    }
+
+    fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
+        for rocket in &self.rockets {
+            self.render_point(renderer, rocket.x, rocket.y, '|', Color::DarkYellow)?;
+        }
+
+        for spark in &self.sparks {
+            self.render_point(
+                renderer,
+                spark.x,
+                spark.y,
+                spark.get_character(),
+                spark.color,
+            )?;
+        }
+
+        Ok(())
+    }
+}
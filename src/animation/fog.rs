@@ -1,3 +1,4 @@
+use crate::animation::{FrameContext, Layer, ParticleSystem};
 use crate::render::TerminalRenderer;
 use crate::weather::types::FogIntensity;
 use crossterm::style::Color;
@@ -48,8 +49,8 @@ impl FogWisp {
         }
     }
 
-    fn update(&mut self) {
-        self.x += self.speed_x;
+    fn update(&mut self, wind_x: f32) {
+        self.x += self.speed_x + wind_x;
         self.lifetime += 1;
     }
 
@@ -66,10 +67,17 @@ pub struct FogSystem {
     terminal_height: u16,
     intensity: FogIntensity,
     spawn_timer: u32,
+    wind_x: f32,
+    density: f32,
 }
 
 impl FogSystem {
-    pub fn new(terminal_width: u16, terminal_height: u16, intensity: FogIntensity) -> Self {
+    pub fn new(
+        terminal_width: u16,
+        terminal_height: u16,
+        intensity: FogIntensity,
+        density: f32,
+    ) -> Self {
         let wisps_capacity = match intensity {
             FogIntensity::Light => (terminal_width as f32 * 0.3) as usize,
             FogIntensity::Medium => (terminal_width as f32 * 0.6) as usize,
@@ -82,6 +90,8 @@ impl FogSystem {
             terminal_height,
             intensity,
             spawn_timer: 0,
+            wind_x: 0.0,
+            density,
         }
     }
 
@@ -89,12 +99,36 @@ impl FogSystem {
         self.intensity = intensity;
     }
 
-    pub fn update(&mut self, terminal_width: u16, terminal_height: u16, rng: &mut impl Rng) {
+    pub fn set_wind(&mut self, speed_kmh: f32, direction_deg: f32) {
+        let speed_factor = speed_kmh / 200.0;
+        let direction_rad = direction_deg.to_radians();
+        let x_component = -direction_rad.sin();
+        self.wind_x = speed_factor * x_component;
+    }
+}
+
+impl ParticleSystem for FogSystem {
+    fn layer(&self) -> Layer {
+        Layer::Weather
+    }
+
+    fn is_active(&self, ctx: &FrameContext) -> bool {
+        ctx.conditions.is_foggy
+    }
+
+    fn update(&mut self, ctx: &mut FrameContext) {
+        if let Some(weather) = ctx.weather {
+            self.set_intensity(weather.condition.fog_intensity());
+        }
+        self.set_wind(ctx.wind.speed_kmh, ctx.wind.direction_deg);
+        let (terminal_width, terminal_height) = (ctx.width, ctx.height);
+        let rng = &mut ctx.rng;
+
         self.terminal_width = terminal_width;
         self.terminal_height = terminal_height;
 
         for wisp in &mut self.wisps {
-            wisp.update();
+            wisp.update(self.wind_x);
         }
 
         self.wisps.retain(|w| w.is_alive(terminal_width));
@@ -104,7 +138,7 @@ impl FogSystem {
             FogIntensity::Medium => (0.6, 2),
             FogIntensity::Heavy => (1.0, 1),
         };
-        let target_count = (terminal_width as f32 * target_multiplier) as usize;
+        let target_count = (terminal_width as f32 * target_multiplier * self.density) as usize;
 
         self.spawn_timer += 1;
         if self.spawn_timer >= spawn_delay && self.wisps.len() < target_count {
@@ -118,7 +152,7 @@ impl FogSystem {
         }
     }
 
-    pub fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
+    fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
         for wisp in &self.wisps {
             let x = wisp.x as i16;
             let y = wisp.y as i16;
@@ -0,0 +1,138 @@
+use rand::prelude::*;
+
+const ATTACK_FRAMES: u32 = 12;
+const DECAY_FRAMES: u32 = 36;
+const MIN_GUST_INTERVAL: u32 = 90;
+const MAX_GUST_INTERVAL: u32 = 300;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum GustPhase {
+    Calm,
+    Rising,
+    Falling,
+}
+
+/// Periodic wind bursts on top of the mean wind. The size of each burst is
+/// scaled to the gap between the reported gust speed and the mean speed.
+pub struct GustModel {
+    mean_speed: f32,
+    gust_speed: f32,
+    phase: GustPhase,
+    timer: u32,
+    next_gust_in: u32,
+    peak: f32,
+    strength: f32,
+}
+
+impl GustModel {
+    pub fn new() -> Self {
+        Self {
+            mean_speed: 0.0,
+            gust_speed: 0.0,
+            phase: GustPhase::Calm,
+            timer: 0,
+            next_gust_in: MIN_GUST_INTERVAL,
+            peak: 0.0,
+            strength: 0.0,
+        }
+    }
+
+    pub fn set_wind(&mut self, mean_speed: f32, gust_speed: Option<f32>) {
+        self.mean_speed = mean_speed;
+        self.gust_speed = gust_speed.unwrap_or(mean_speed).max(mean_speed);
+    }
+
+    pub fn has_gusts(&self) -> bool {
+        self.gust_speed - self.mean_speed > f32::EPSILON
+    }
+
+    pub fn update(&mut self, rng: &mut impl Rng) {
+        if !self.has_gusts() {
+            self.phase = GustPhase::Calm;
+            self.strength = 0.0;
+            return;
+        }
+
+        self.timer += 1;
+        match self.phase {
+            GustPhase::Calm => {
+                if self.timer >= self.next_gust_in {
+                    self.phase = GustPhase::Rising;
+                    self.timer = 0;
+                    self.peak = 0.5 + rng.random::<f32>() * 0.5;
+                }
+            }
+            GustPhase::Rising => {
+                self.strength = self.peak * (self.timer as f32 / ATTACK_FRAMES as f32);
+                if self.timer >= ATTACK_FRAMES {
+                    self.phase = GustPhase::Falling;
+                    self.timer = 0;
+                }
+            }
+            GustPhase::Falling => {
+                self.strength = self.peak * (1.0 - self.timer as f32 / DECAY_FRAMES as f32);
+                if self.timer >= DECAY_FRAMES {
+                    self.phase = GustPhase::Calm;
+                    self.timer = 0;
+                    self.strength = 0.0;
+                    self.next_gust_in = MIN_GUST_INTERVAL
+                        + rng.random::<u32>() % (MAX_GUST_INTERVAL - MIN_GUST_INTERVAL);
+                }
+            }
+        }
+    }
+
+    /// Mean wind plus the contribution of the current burst.
+    pub fn current_speed(&self) -> f32 {
+        self.mean_speed + (self.gust_speed - self.mean_speed) * self.strength
+    }
+}
+
+impl Default for GustModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_no_gusts_keeps_mean_speed() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut model = GustModel::new();
+        model.set_wind(18.0, None);
+
+        for _ in 0..1000 {
+            model.update(&mut rng);
+            assert_eq!(model.current_speed(), 18.0);
+        }
+    }
+
+    #[test]
+    fn test_gusts_stay_between_mean_and_gust_speed() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut model = GustModel::new();
+        model.set_wind(18.0, Some(45.0));
+
+        let mut max_seen: f32 = 0.0;
+        for _ in 0..2000 {
+            model.update(&mut rng);
+            let speed = model.current_speed();
+            assert!((18.0..=45.0).contains(&speed));
+            max_seen = max_seen.max(speed);
+        }
+
+        // A burst reaches at least half of the gap
+        assert!(max_seen >= 18.0 + 27.0 * 0.5 - 0.01);
+    }
+
+    #[test]
+    fn test_gust_below_mean_is_ignored() {
+        let mut model = GustModel::new();
+        model.set_wind(20.0, Some(10.0));
+        assert!(!model.has_gusts());
+    }
+}
@@ -1,8 +1,12 @@
+use crate::animation::{FrameContext, Layer, ParticleSystem, scaled};
 use crate::render::TerminalRenderer;
+use crate::scene::collision::CollisionMask;
 use crossterm::style::Color;
 use rand::prelude::*;
 use std::io;
 
+const REST_DURATION: u16 = 180;
+
 struct Leaf {
     x: f32,
     y: f32,
@@ -13,6 +17,7 @@ struct Leaf {
     rotation: u8,
     color: Color,
     character: char,
+    resting_for: Option<u16>,
 }
 
 impl Leaf {
@@ -76,10 +81,22 @@ impl Leaf {
             rotation: 0,
             color,
             character,
+            resting_for: None,
         }
     }
 
-    fn update(&mut self) {
+    fn update(&mut self, mask: &CollisionMask, wind_x: f32) {
+        if let Some(rest) = self.resting_for.as_mut() {
+            *rest += 1;
+            return;
+        }
+
+        // Come to rest on a roof, canopy or the ground below
+        if mask.is_solid(self.x, self.y + 1.0) {
+            self.resting_for = Some(0);
+            return;
+        }
+
         self.y += self.fall_speed;
 
         self.sway_phase += self.sway_speed;
@@ -88,13 +105,18 @@ impl Leaf {
         }
 
         let sway_offset = self.sway_phase.sin() * self.sway_amplitude;
-        self.x += sway_offset * 0.1;
+        // Lighter leaves (bigger sway) catch more of the wind
+        self.x += sway_offset * 0.1 + wind_x * (0.5 + self.sway_amplitude * 0.5);
 
         self.rotation = ((self.sway_phase * 2.0).sin() * 4.0) as u8;
     }
 
-    fn is_offscreen(&self, terminal_height: u16) -> bool {
-        self.y > terminal_height as f32
+    fn is_offscreen(&self, terminal_width: u16, terminal_height: u16) -> bool {
+        self.y > terminal_height as f32 || self.x < -10.0 || self.x > terminal_width as f32 + 10.0
+    }
+
+    fn is_done_resting(&self) -> bool {
+        self.resting_for.is_some_and(|rest| rest >= REST_DURATION)
     }
 
     fn get_character(&self) -> char {
@@ -125,15 +147,17 @@ pub struct FallingLeaves {
     spawn_rate: u32,
     terminal_width: u16,
     terminal_height: u16,
+    wind_x: f32,
+    max_leaves: usize,
 }
 
 impl FallingLeaves {
-    pub fn new(terminal_width: u16, terminal_height: u16) -> Self {
+    pub fn new(terminal_width: u16, terminal_height: u16, density: f32) -> Self {
         let mut rng = rand::rng();
-        let initial_count = std::cmp::max(5, terminal_width / 10);
+        let initial_count = scaled(std::cmp::max(5, terminal_width / 10) as usize, density);
 
-        let max_capacity = std::cmp::max(10, terminal_width / 8) as usize;
-        let mut leaves = Vec::with_capacity(max_capacity);
+        let max_leaves = scaled(std::cmp::max(10, terminal_width / 8) as usize, density);
+        let mut leaves = Vec::with_capacity(max_leaves);
 
         for _ in 0..initial_count {
             leaves.push(Leaf::new(terminal_width, false, &mut rng));
@@ -145,18 +169,42 @@ impl FallingLeaves {
             spawn_rate: 15,
             terminal_width,
             terminal_height,
+            wind_x: 0.0,
+            max_leaves,
         }
     }
 
-    pub fn update(&mut self, terminal_width: u16, terminal_height: u16, rng: &mut impl Rng) {
+    pub fn set_wind(&mut self, speed_kmh: f32, direction_deg: f32) {
+        let speed_factor = speed_kmh / 50.0;
+        let direction_rad = direction_deg.to_radians();
+        let x_component = -direction_rad.sin();
+        self.wind_x = speed_factor * x_component;
+    }
+}
+
+impl ParticleSystem for FallingLeaves {
+    fn layer(&self) -> Layer {
+        Layer::Weather
+    }
+
+    fn is_active(&self, ctx: &FrameContext) -> bool {
+        !ctx.conditions.is_precipitating()
+    }
+
+    fn update(&mut self, ctx: &mut FrameContext) {
+        self.set_wind(ctx.wind.gust_speed_kmh, ctx.wind.direction_deg);
+        let (terminal_width, terminal_height, mask) = (ctx.width, ctx.height, ctx.mask);
+        let rng = &mut ctx.rng;
+
         self.terminal_width = terminal_width;
         self.terminal_height = terminal_height;
 
         for leaf in &mut self.leaves {
-            leaf.update();
+            leaf.update(mask, self.wind_x);
         }
 
-        self.leaves.retain(|l| !l.is_offscreen(terminal_height));
+        self.leaves
+            .retain(|l| !l.is_offscreen(terminal_width, terminal_height) && !l.is_done_resting());
 
         self.spawn_counter += 1;
         if self.spawn_counter >= self.spawn_rate {
@@ -166,13 +214,12 @@ impl FallingLeaves {
             }
         }
 
-        let max_leaves = std::cmp::max(10, terminal_width / 8) as usize;
-        if self.leaves.len() > max_leaves {
-            self.leaves.truncate(max_leaves);
+        if self.leaves.len() > self.max_leaves {
+            self.leaves.truncate(self.max_leaves);
         }
     }
 
-    pub fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
+    fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
         for leaf in &self.leaves {
             let x = leaf.x as i16;
             let y = leaf.y as i16;
@@ -1,21 +1,102 @@
 pub mod airplanes;
 pub mod birds;
 pub mod chimney;
+pub mod clock;
 pub mod clouds;
 pub mod fireflies;
+pub mod fireworks;
 pub mod fog;
+pub mod gusts;
 pub mod leaves;
 pub mod moon;
 pub mod raindrops;
+pub mod registry;
 pub mod snow;
 pub mod stars;
 pub mod sunny;
 pub mod thunderstorm;
 
 use crate::render::TerminalRenderer;
+use crate::scene::collision::CollisionMask;
+use crate::scene::events::Holiday;
+use crate::weather::{WeatherConditions, WeatherData};
 use crossterm::style::Color;
+use rand::Rng;
 use std::io;
 
+/// Scales a particle count by a density multiplier from the config.
+pub fn scaled(count: usize, density: f32) -> usize {
+    (count as f32 * density).round() as usize
+}
+
+/// Wind as felt by the particle systems during a frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Wind {
+    /// Mean speed from the latest weather report
+    pub speed_kmh: f32,
+    /// Mean speed with the current gust on top
+    pub gust_speed_kmh: f32,
+    /// Where the wind blows from, in degrees
+    pub direction_deg: f32,
+}
+
+/// Everything a particle system can look at while it advances a step.
+pub struct FrameContext<'a> {
+    pub width: u16,
+    pub height: u16,
+    /// First row of the ground
+    pub horizon_y: u16,
+    /// Chimney tops and other points of the scene that give off smoke
+    pub emitters: &'a [(u16, u16)],
+    pub mask: &'a CollisionMask,
+    pub conditions: &'a WeatherConditions,
+    /// Latest weather report, `None` while the first one is loading
+    pub weather: Option<&'a WeatherData>,
+    pub holidays: &'a [Holiday],
+    pub wind: Wind,
+    pub rng: &'a mut dyn Rng,
+}
+
+/// Draw order of the particle systems. The scene is drawn between
+/// `Atmosphere` and `Smoke`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Layer {
+    /// Sun, moon and stars
+    Sky,
+    /// Clouds and anything flying in front of the sky
+    Atmosphere,
+    /// Smoke rising from the scene's emitters
+    Smoke,
+    /// Precipitation and fog in front of everything else
+    Weather,
+}
+
+impl Layer {
+    pub fn is_behind_scene(self) -> bool {
+        self < Layer::Smoke
+    }
+}
+
+/// A weather or ambient effect that lives on one layer of the screen.
+pub trait ParticleSystem {
+    fn layer(&self) -> Layer;
+
+    /// Whether the effect shows up under this frame's conditions. Inactive
+    /// systems are neither updated nor drawn.
+    fn is_active(&self, _ctx: &FrameContext) -> bool {
+        true
+    }
+
+    fn update(&mut self, ctx: &mut FrameContext);
+
+    fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()>;
+
+    /// Columns where particles reached the ground during the last update.
+    fn ground_impacts(&self) -> &[u16] {
+        &[]
+    }
+}
+
 pub trait Animation {
     fn get_frame(&self, frame_number: usize) -> &[String];
     fn frame_count(&self) -> usize;
@@ -1,3 +1,4 @@
+use crate::animation::{FrameContext, Layer, ParticleSystem};
 use crate::render::TerminalRenderer;
 use crossterm::style::Color;
 use std::io;
@@ -21,13 +22,24 @@ impl MoonSystem {
     pub fn set_phase(&mut self, phase: f64) {
         self.phase = phase;
     }
+}
+
+impl ParticleSystem for MoonSystem {
+    fn layer(&self) -> Layer {
+        Layer::Sky
+    }
+
+    fn is_active(&self, ctx: &FrameContext) -> bool {
+        !ctx.conditions.is_day
+    }
 
-    pub fn update(&mut self, terminal_width: u16, terminal_height: u16) {
+    fn update(&mut self, ctx: &mut FrameContext) {
+        let (terminal_width, terminal_height) = (ctx.width, ctx.height);
         self.x = (terminal_width / 4 * 3).min(terminal_width.saturating_sub(15));
         self.y = (terminal_height / 4).max(2);
     }
 
-    pub fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
+    fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
         let step = (self.phase * 8.0).round() as usize % 8;
 
         let art = match step {
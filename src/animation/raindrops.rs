@@ -1,3 +1,4 @@
+use crate::animation::{FrameContext, Layer, ParticleSystem, scaled};
 use crate::render::TerminalRenderer;
 use crate::weather::types::RainIntensity;
 use crossterm::style::Color;
@@ -33,10 +34,18 @@ pub struct RaindropSystem {
     terminal_height: u16,
     intensity: RainIntensity,
     wind_x: f32,
+    ground_level: Option<u16>,
+    ground_impacts: Vec<u16>,
+    density: f32,
 }
 
 impl RaindropSystem {
-    pub fn new(terminal_width: u16, terminal_height: u16, intensity: RainIntensity) -> Self {
+    pub fn new(
+        terminal_width: u16,
+        terminal_height: u16,
+        intensity: RainIntensity,
+        density: f32,
+    ) -> Self {
         let drops_capacity = match intensity {
             RainIntensity::Drizzle => (terminal_width / 4) as usize,
             RainIntensity::Light => (terminal_width / 2) as usize,
@@ -52,6 +61,9 @@ impl RaindropSystem {
             terminal_height,
             intensity,
             wind_x: 0.0,
+            ground_level: None,
+            ground_impacts: Vec::with_capacity(20),
+            density,
         };
         let wind_dir = if rand::random::<bool>() { 1.0 } else { -1.0 };
         system.set_intensity_with_dir(intensity, wind_dir);
@@ -81,6 +93,12 @@ impl RaindropSystem {
         self.wind_x = speed_factor * x_component;
     }
 
+    /// Sets the top row of the ground so drops landing on it can be reported
+    /// through `ground_impacts`.
+    pub fn set_ground_level(&mut self, y: u16) {
+        self.ground_level = Some(y);
+    }
+
     fn spawn_drop(&mut self, rng: &mut impl Rng) {
         let x = (rng.random::<u32>() % (self.terminal_width as u32 * 2)) as f32
             - (self.terminal_width as f32 * 0.5);
@@ -142,17 +160,38 @@ impl RaindropSystem {
             z_index,
         });
     }
+}
+
+impl ParticleSystem for RaindropSystem {
+    fn layer(&self) -> Layer {
+        Layer::Weather
+    }
+
+    fn is_active(&self, ctx: &FrameContext) -> bool {
+        ctx.conditions.is_raining || ctx.conditions.is_thunderstorm
+    }
+
+    fn update(&mut self, ctx: &mut FrameContext) {
+        if let Some(weather) = ctx.weather {
+            self.set_intensity(weather.condition.rain_intensity());
+        }
+        self.set_wind(ctx.wind.gust_speed_kmh, ctx.wind.direction_deg);
+        self.set_ground_level(ctx.horizon_y);
+        let (terminal_width, terminal_height, mask) = (ctx.width, ctx.height, ctx.mask);
+        let rng = &mut ctx.rng;
 
-    pub fn update(&mut self, terminal_width: u16, terminal_height: u16, rng: &mut impl Rng) {
         self.terminal_width = terminal_width;
         self.terminal_height = terminal_height;
 
-        let target_count = match self.intensity {
-            RainIntensity::Drizzle => (terminal_width / 4) as usize,
-            RainIntensity::Light => (terminal_width / 2) as usize,
-            RainIntensity::Heavy => terminal_width as usize,
-            RainIntensity::Storm => (terminal_width as f32 * 1.5) as usize,
-        };
+        let target_count = scaled(
+            match self.intensity {
+                RainIntensity::Drizzle => (terminal_width / 4) as usize,
+                RainIntensity::Light => (terminal_width / 2) as usize,
+                RainIntensity::Heavy => terminal_width as usize,
+                RainIntensity::Storm => (terminal_width as f32 * 1.5) as usize,
+            },
+            self.density,
+        );
 
         if self.drops.len() < target_count {
             let spawn_rate = match self.intensity {
@@ -167,6 +206,10 @@ impl RaindropSystem {
 
         // Update drops
         let new_splashes = &mut self.new_splashes;
+        let ground_impacts = &mut self.ground_impacts;
+        let ground_level = self.ground_level;
+        let wind_x = self.wind_x;
+        ground_impacts.clear();
         let splash_chance = match self.intensity {
             RainIntensity::Drizzle => 0.1,
             RainIntensity::Light => 0.3,
@@ -174,10 +217,29 @@ impl RaindropSystem {
         };
 
         self.drops.retain_mut(|drop| {
+            // Drops ease towards the current wind so gusts sweep through the rain
+            drop.speed_x += (wind_x - drop.speed_x) * 0.1;
             drop.y += drop.speed_y;
             drop.x += drop.speed_x;
 
-            // Hit ground?
+            // Landed on the house, a decoration or the ground?
+            if mask.is_solid(drop.x, drop.y) {
+                let y = drop.y as u16;
+                if ground_level.is_some_and(|level| y >= level) {
+                    ground_impacts.push(drop.x as u16);
+                }
+                if drop.z_index == 1 && rng.random::<f32>() < splash_chance {
+                    new_splashes.push_back(Splash {
+                        x: drop.x as u16,
+                        y: y.saturating_sub(1),
+                        timer: 0,
+                        max_timer: 3,
+                    });
+                }
+                return false;
+            }
+
+            // Hit bottom of the screen?
             if drop.y >= (terminal_height - 1) as f32 {
                 if drop.z_index == 1 && rng.random::<f32>() < splash_chance {
                     new_splashes.push_back(Splash {
@@ -210,7 +272,7 @@ impl RaindropSystem {
         });
     }
 
-    pub fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
+    fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
         // Render drops
         for drop in &self.drops {
             let x = drop.x as i16;
@@ -250,4 +312,8 @@ impl RaindropSystem {
 
         Ok(())
     }
+
+    fn ground_impacts(&self) -> &[u16] {
+        &self.ground_impacts
+    }
 }
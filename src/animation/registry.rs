@@ -0,0 +1,101 @@
+use super::{FrameContext, Layer, ParticleSystem};
+use crate::render::TerminalRenderer;
+use std::io;
+
+/// Particle systems sorted by layer. Systems on the same layer are drawn in
+/// the order they were registered.
+#[derive(Default)]
+pub struct LayerRegistry {
+    systems: Vec<Box<dyn ParticleSystem>>,
+}
+
+impl LayerRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, system: impl ParticleSystem + 'static) {
+        let layer = system.layer();
+        let index = self.systems.partition_point(|s| s.layer() <= layer);
+        self.systems.insert(index, Box::new(system));
+    }
+
+    /// Advances every system that is active under this frame's conditions.
+    pub fn update(&mut self, ctx: &mut FrameContext) {
+        for system in &mut self.systems {
+            if system.is_active(ctx) {
+                system.update(ctx);
+            }
+        }
+    }
+
+    /// Draws the active systems whose layer passes `filter`, back to front.
+    pub fn render(
+        &self,
+        renderer: &mut TerminalRenderer,
+        ctx: &FrameContext,
+        filter: impl Fn(Layer) -> bool,
+    ) -> io::Result<()> {
+        for system in &self.systems {
+            if filter(system.layer()) && system.is_active(ctx) {
+                system.render(renderer)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Ground impacts reported by every system during its last update.
+    pub fn ground_impacts(&self) -> Vec<u16> {
+        self.systems
+            .iter()
+            .flat_map(|s| s.ground_impacts())
+            .copied()
+            .collect()
+    }
+
+    /// Layers of the registered systems in draw order.
+    #[cfg(test)]
+    fn layers(&self) -> Vec<Layer> {
+        self.systems.iter().map(|s| s.layer()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Marker(Layer);
+
+    impl ParticleSystem for Marker {
+        fn layer(&self) -> Layer {
+            self.0
+        }
+
+        fn update(&mut self, _ctx: &mut FrameContext) {}
+
+        fn render(&self, _renderer: &mut TerminalRenderer) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_systems_are_sorted_by_layer() {
+        let mut registry = LayerRegistry::new();
+        registry.register(Marker(Layer::Weather));
+        registry.register(Marker(Layer::Sky));
+        registry.register(Marker(Layer::Smoke));
+        registry.register(Marker(Layer::Sky));
+        registry.register(Marker(Layer::Atmosphere));
+
+        assert_eq!(
+            registry.layers(),
+            vec![
+                Layer::Sky,
+                Layer::Sky,
+                Layer::Atmosphere,
+                Layer::Smoke,
+                Layer::Weather
+            ]
+        );
+    }
+}
@@ -1,9 +1,12 @@
+use crate::animation::{FrameContext, Layer, ParticleSystem, scaled};
 use crate::render::TerminalRenderer;
 use crate::weather::types::SnowIntensity;
 use crossterm::style::Color;
 use rand::prelude::*;
 use std::io;
 
+const SETTLED_LIFETIME: u16 = 600;
+
 struct Snowflake {
     x: f32,
     y: f32,
@@ -14,16 +17,30 @@ struct Snowflake {
     color: Color,
 }
 
+/// A flake resting on a roof, tree canopy or the ground until it melts.
+struct SettledFlake {
+    x: u16,
+    y: u16,
+    age: u16,
+}
+
 pub struct SnowSystem {
     flakes: Vec<Snowflake>,
+    settled: Vec<SettledFlake>,
     terminal_width: u16,
     terminal_height: u16,
     intensity: SnowIntensity,
     wind_x: f32,
+    density: f32,
 }
 
 impl SnowSystem {
-    pub fn new(terminal_width: u16, terminal_height: u16, intensity: SnowIntensity) -> Self {
+    pub fn new(
+        terminal_width: u16,
+        terminal_height: u16,
+        intensity: SnowIntensity,
+        density: f32,
+    ) -> Self {
         let flakes_capacity = match intensity {
             SnowIntensity::Light => (terminal_width / 4) as usize,
             SnowIntensity::Medium => (terminal_width / 2) as usize,
@@ -32,10 +49,12 @@ impl SnowSystem {
 
         let mut system = Self {
             flakes: Vec::with_capacity(flakes_capacity),
+            settled: Vec::with_capacity(flakes_capacity),
             terminal_width,
             terminal_height,
             intensity,
             wind_x: 0.0,
+            density,
         };
         // Initialize with some default wind
         let wind_dir = if rand::random::<bool>() { 0.2 } else { -0.2 };
@@ -95,16 +114,36 @@ impl SnowSystem {
             },
         });
     }
+}
+
+impl ParticleSystem for SnowSystem {
+    fn layer(&self) -> Layer {
+        Layer::Weather
+    }
+
+    fn is_active(&self, ctx: &FrameContext) -> bool {
+        ctx.conditions.is_snowing && !ctx.conditions.is_raining && !ctx.conditions.is_thunderstorm
+    }
+
+    fn update(&mut self, ctx: &mut FrameContext) {
+        if let Some(weather) = ctx.weather {
+            self.set_intensity(weather.condition.snow_intensity());
+        }
+        self.set_wind(ctx.wind.gust_speed_kmh, ctx.wind.direction_deg);
+        let (terminal_width, terminal_height, mask) = (ctx.width, ctx.height, ctx.mask);
+        let rng = &mut ctx.rng;
 
-    pub fn update(&mut self, terminal_width: u16, terminal_height: u16, rng: &mut impl Rng) {
         self.terminal_width = terminal_width;
         self.terminal_height = terminal_height;
 
-        let target_count = match self.intensity {
-            SnowIntensity::Light => (terminal_width / 4) as usize,
-            SnowIntensity::Medium => (terminal_width / 2) as usize,
-            SnowIntensity::Heavy => terminal_width as usize,
-        };
+        let target_count = scaled(
+            match self.intensity {
+                SnowIntensity::Light => (terminal_width / 4) as usize,
+                SnowIntensity::Medium => (terminal_width / 2) as usize,
+                SnowIntensity::Heavy => terminal_width as usize,
+            },
+            self.density,
+        );
 
         if self.flakes.len() < target_count {
             let spawn_rate = match self.intensity {
@@ -117,13 +156,32 @@ impl SnowSystem {
             }
         }
 
+        let settled = &mut self.settled;
+        let max_settled = terminal_width as usize * 2;
+        let wind_x = self.wind_x;
+
         self.flakes.retain_mut(|flake| {
+            // Flakes slowly follow the current wind so gusts carry them along
+            flake.speed_x += (wind_x - flake.speed_x) * 0.05;
             flake.y += flake.speed_y;
 
             // Add horizontal sway
             let sway = (flake.y * 0.2 + flake.sway_offset).sin() * 0.05;
             flake.x += flake.speed_x + sway;
 
+            // Settle on top of whatever the flake landed on
+            if mask.is_solid(flake.x, flake.y) {
+                let x = flake.x as u16;
+                let y = (flake.y as u16).saturating_sub(1);
+                if settled.len() < max_settled
+                    && !mask.is_solid(flake.x, y as f32)
+                    && !settled.iter().any(|s| s.x == x && s.y == y)
+                {
+                    settled.push(SettledFlake { x, y, age: 0 });
+                }
+                return false;
+            }
+
             // Hit ground or out of bounds
             if flake.y >= (terminal_height - 1) as f32 {
                 return false;
@@ -136,9 +194,14 @@ impl SnowSystem {
 
             true
         });
+
+        self.settled.retain_mut(|flake| {
+            flake.age += 1;
+            flake.age < SETTLED_LIFETIME && flake.x < terminal_width && flake.y < terminal_height
+        });
     }
 
-    pub fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
+    fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
         for flake in &self.flakes {
             let x = flake.x as i16;
             let y = flake.y as i16;
@@ -148,6 +211,15 @@ impl SnowSystem {
                 renderer.render_char(x as u16, y as u16, flake.character, flake.color)?;
             }
         }
+
+        for flake in &self.settled {
+            let ch = if flake.age > SETTLED_LIFETIME * 3 / 4 {
+                '.'
+            } else {
+                '_'
+            };
+            renderer.render_char(flake.x, flake.y, ch, Color::White)?;
+        }
         Ok(())
     }
 }
@@ -1,3 +1,4 @@
+use crate::animation::{FrameContext, Layer, ParticleSystem, scaled};
 use crate::render::TerminalRenderer;
 use crossterm::style::Color;
 use rand::prelude::*;
@@ -27,9 +28,12 @@ pub struct StarSystem {
 }
 
 impl StarSystem {
-    pub fn new(terminal_width: u16, terminal_height: u16) -> Self {
+    pub fn new(terminal_width: u16, terminal_height: u16, density: f32) -> Self {
         let mut rng = rand::rng();
-        let count = (terminal_width as usize * terminal_height as usize) / 80; // Density
+        let count = scaled(
+            (terminal_width as usize * terminal_height as usize) / 80,
+            density,
+        );
         let mut stars = Vec::with_capacity(count);
         const MIN_DISTANCE: f32 = 3.0; // Minimum distance between stars
 
@@ -70,8 +74,21 @@ impl StarSystem {
             terminal_height,
         }
     }
+}
+
+impl ParticleSystem for StarSystem {
+    fn layer(&self) -> Layer {
+        Layer::Sky
+    }
+
+    fn is_active(&self, ctx: &FrameContext) -> bool {
+        !ctx.conditions.is_day
+    }
+
+    fn update(&mut self, ctx: &mut FrameContext) {
+        let (terminal_width, terminal_height) = (ctx.width, ctx.height);
+        let rng = &mut ctx.rng;
 
-    pub fn update(&mut self, terminal_width: u16, terminal_height: u16, rng: &mut impl Rng) {
         self.terminal_width = terminal_width;
         self.terminal_height = terminal_height;
 
@@ -104,7 +121,7 @@ impl StarSystem {
         }
     }
 
-    pub fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
+    fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
         for star in &self.stars {
             let ch = if star.brightness > 0.8 {
                 '*'
@@ -1,5 +1,12 @@
-use super::Animation;
+use super::clock::SIMULATION_HZ;
+use super::{Animation, AnimationController, FrameContext, Layer, ParticleSystem};
+use crate::render::TerminalRenderer;
+use crate::weather::WeatherCondition;
 use crossterm::style::Color;
+use std::io;
+
+/// Steps each frame of the sun stays on screen, half a second
+const FRAME_STEPS: u32 = SIMULATION_HZ / 2;
 
 pub struct SunnyAnimation {
     frames: Vec<Vec<String>>,
@@ -60,3 +67,62 @@ impl Default for SunnyAnimation {
         Self::new()
     }
 }
+
+/// The sun shining in the middle of the sky on fair days.
+pub struct SunSystem {
+    animation: SunnyAnimation,
+    controller: AnimationController,
+    steps: u32,
+    y: u16,
+}
+
+impl SunSystem {
+    pub fn new() -> Self {
+        Self {
+            animation: SunnyAnimation::new(),
+            controller: AnimationController::new(),
+            steps: 0,
+            y: 2,
+        }
+    }
+}
+
+impl Default for SunSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParticleSystem for SunSystem {
+    fn layer(&self) -> Layer {
+        Layer::Sky
+    }
+
+    fn is_active(&self, ctx: &FrameContext) -> bool {
+        ctx.conditions.is_day
+            && !ctx.conditions.is_precipitating()
+            && ctx.weather.is_some_and(|weather| {
+                matches!(
+                    weather.condition,
+                    WeatherCondition::Clear
+                        | WeatherCondition::PartlyCloudy
+                        | WeatherCondition::Cloudy
+                )
+            })
+    }
+
+    fn update(&mut self, ctx: &mut FrameContext) {
+        self.y = if ctx.height > 20 { 3 } else { 2 };
+
+        self.steps += 1;
+        if self.steps >= FRAME_STEPS {
+            self.controller.next_frame(&self.animation);
+            self.steps = 0;
+        }
+    }
+
+    fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
+        self.controller
+            .render_frame(renderer, &self.animation, self.y)
+    }
+}
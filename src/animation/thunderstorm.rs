@@ -1,3 +1,4 @@
+use crate::animation::{FrameContext, Layer, ParticleSystem};
 use crate::render::TerminalRenderer;
 use crossterm::style::Color;
 use rand::prelude::*;
@@ -29,10 +30,11 @@ pub struct ThunderstormSystem {
     terminal_height: u16,
     flash_active: bool,
     next_strike_in: u16,
+    density: f32,
 }
 
 impl ThunderstormSystem {
-    pub fn new(terminal_width: u16, terminal_height: u16) -> Self {
+    pub fn new(terminal_width: u16, terminal_height: u16, density: f32) -> Self {
         Self {
             bolts: VecDeque::with_capacity(MAX_BOLTS),
             state: LightningState::Idle,
@@ -41,6 +43,7 @@ impl ThunderstormSystem {
             terminal_height,
             flash_active: false,
             next_strike_in: 60 + (rand::random::<u16>() % 120), // Random start delay
+            density,
         }
     }
 
@@ -102,8 +105,21 @@ impl ThunderstormSystem {
             self.bolts.pop_front();
         }
     }
+}
+
+impl ParticleSystem for ThunderstormSystem {
+    fn layer(&self) -> Layer {
+        Layer::Weather
+    }
+
+    fn is_active(&self, ctx: &FrameContext) -> bool {
+        ctx.conditions.is_thunderstorm
+    }
+
+    fn update(&mut self, ctx: &mut FrameContext) {
+        let (terminal_width, terminal_height) = (ctx.width, ctx.height);
+        let rng = &mut ctx.rng;
 
-    pub fn update(&mut self, terminal_width: u16, terminal_height: u16, rng: &mut impl Rng) {
         self.terminal_width = terminal_width;
         self.terminal_height = terminal_height;
 
@@ -145,17 +161,15 @@ impl ThunderstormSystem {
                 if self.bolts.is_empty() {
                     self.state = LightningState::Idle;
                     self.timer = 0;
-                    self.next_strike_in = 30 + (rng.random::<u16>() % 200);
+                    // Higher densities shorten the gap between strikes
+                    let gap = 30 + (rng.random::<u16>() % 200);
+                    self.next_strike_in = (gap as f32 / self.density) as u16;
                 }
             }
         }
     }
 
-    pub fn is_flashing(&self) -> bool {
-        self.flash_active
-    }
-
-    pub fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
+    fn render(&self, renderer: &mut TerminalRenderer) -> io::Result<()> {
         let color = if self.flash_active {
             Color::White
         } else {
@@ -167,6 +181,10 @@ impl ThunderstormSystem {
                 renderer.render_char(segment.0, segment.1, segment.2, color)?;
             }
         }
+
+        if self.flash_active {
+            renderer.flash_screen()?;
+        }
         Ok(())
     }
 }
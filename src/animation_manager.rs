@@ -1,238 +1,180 @@
 use crate::animation::{
-    AnimationController, airplanes::AirplaneSystem, birds::BirdSystem, chimney::ChimneySmoke,
-    clouds::CloudSystem, fireflies::FireflySystem, fog::FogSystem, leaves::FallingLeaves,
-    moon::MoonSystem, raindrops::RaindropSystem, snow::SnowSystem, stars::StarSystem,
-    sunny::SunnyAnimation, thunderstorm::ThunderstormSystem,
+    FrameContext, Layer, Wind, airplanes::AirplaneSystem, birds::BirdSystem, chimney::ChimneySmoke,
+    clouds::CloudSystem, fireflies::FireflySystem, fireworks::FireworkSystem, fog::FogSystem,
+    gusts::GustModel, leaves::FallingLeaves, moon::MoonSystem, raindrops::RaindropSystem,
+    registry::LayerRegistry, snow::SnowSystem, stars::StarSystem, sunny::SunSystem,
+    thunderstorm::ThunderstormSystem,
 };
-use crate::app_state::AppState;
+use crate::config::AnimationsConfig;
 use crate::render::TerminalRenderer;
-use crate::scene::WorldScene;
-use crate::scene::house::House;
-use crate::weather::{FogIntensity, RainIntensity, SnowIntensity, WeatherConditions};
-use crossterm::style::Color;
+use crate::weather::units::ms_to_kmh;
+use crate::weather::{FogIntensity, RainIntensity, SnowIntensity, WeatherData};
 use std::io;
-use std::time::{Duration, Instant};
-
-const FRAME_DELAY: Duration = Duration::from_millis(500);
 
 pub struct AnimationManager {
-    raindrop_system: RaindropSystem,
-    snow_system: SnowSystem,
-    fog_system: FogSystem,
-    thunderstorm_system: ThunderstormSystem,
-    cloud_system: CloudSystem,
-    bird_system: BirdSystem,
-    airplane_system: AirplaneSystem,
-    star_system: StarSystem,
-    moon_system: MoonSystem,
-    chimney_smoke: ChimneySmoke,
-    firefly_system: FireflySystem,
-    falling_leaves: FallingLeaves,
-    sunny_animation: SunnyAnimation,
-    animation_controller: AnimationController,
-    last_frame_time: Instant,
-    show_leaves: bool,
+    layers: LayerRegistry,
+    gust_model: GustModel,
+    wind: Wind,
 }
 
 impl AnimationManager {
-    pub fn new(term_width: u16, term_height: u16, show_leaves: bool) -> Self {
+    pub fn new(term_width: u16, term_height: u16, config: &AnimationsConfig) -> Self {
+        let (w, h) = (term_width, term_height);
+        let mut layers = LayerRegistry::new();
+
+        if config.stars.is_enabled() {
+            layers.register(StarSystem::new(w, h, config.stars.density));
+        }
+        if config.moon.is_enabled() {
+            layers.register(MoonSystem::new(w, h));
+        }
+        if config.sun.is_enabled() {
+            layers.register(SunSystem::new());
+        }
+        if config.fireflies.is_enabled() {
+            layers.register(FireflySystem::new(w, h, config.fireflies.density));
+        }
+        if config.fireworks.is_enabled() {
+            layers.register(FireworkSystem::new(w, h, config.fireworks.density));
+        }
+        if config.birds.is_enabled() {
+            layers.register(BirdSystem::new(w, h, config.birds.density));
+        }
+        if config.clouds.is_enabled() {
+            layers.register(CloudSystem::new(w, h, config.clouds.density));
+        }
+        if config.airplanes.is_enabled() {
+            layers.register(AirplaneSystem::new(w, h, config.airplanes.density));
+        }
+        if config.smoke.is_enabled() {
+            layers.register(ChimneySmoke::new(config.smoke.density));
+        }
+        if config.rain.is_enabled() {
+            let density = config.rain.density;
+            layers.register(RaindropSystem::new(w, h, RainIntensity::Light, density));
+        }
+        if config.lightning.is_enabled() {
+            layers.register(ThunderstormSystem::new(w, h, config.lightning.density));
+        }
+        if config.snow.is_enabled() {
+            let density = config.snow.density;
+            layers.register(SnowSystem::new(w, h, SnowIntensity::Light, density));
+        }
+        if config.fog.is_enabled() {
+            let density = config.fog.density;
+            layers.register(FogSystem::new(w, h, FogIntensity::Light, density));
+        }
+        if config.leaves.is_enabled() {
+            layers.register(FallingLeaves::new(w, h, config.leaves.density));
+        }
+
         Self {
-            raindrop_system: RaindropSystem::new(term_width, term_height, RainIntensity::Light),
-            snow_system: SnowSystem::new(term_width, term_height, SnowIntensity::Light),
-            fog_system: FogSystem::new(term_width, term_height, FogIntensity::Light),
-            thunderstorm_system: ThunderstormSystem::new(term_width, term_height),
-            cloud_system: CloudSystem::new(term_width, term_height),
-            bird_system: BirdSystem::new(term_width, term_height),
-            airplane_system: AirplaneSystem::new(term_width, term_height),
-            star_system: StarSystem::new(term_width, term_height),
-            moon_system: MoonSystem::new(term_width, term_height),
-            chimney_smoke: ChimneySmoke::new(),
-            firefly_system: FireflySystem::new(term_width, term_height),
-            falling_leaves: FallingLeaves::new(term_width, term_height),
-            sunny_animation: SunnyAnimation::new(),
-            animation_controller: AnimationController::new(),
-            last_frame_time: Instant::now(),
-            show_leaves,
+            layers,
+            gust_model: GustModel::new(),
+            wind: Wind::default(),
         }
     }
 
-    pub fn update_rain_intensity(&mut self, intensity: RainIntensity) {
-        self.raindrop_system.set_intensity(intensity);
+    pub fn update_wind(&mut self, speed_kmh: f32, direction_deg: f32, gusts_kmh: Option<f32>) {
+        self.gust_model.set_wind(speed_kmh, gusts_kmh);
+        self.wind = Wind {
+            speed_kmh,
+            gust_speed_kmh: speed_kmh,
+            direction_deg,
+        };
     }
 
-    pub fn update_snow_intensity(&mut self, intensity: SnowIntensity) {
-        self.snow_system.set_intensity(intensity);
+    /// Advances the gust model. The burst reaches the systems through the
+    /// `gust_speed_kmh` of the next frame's wind.
+    pub fn update_gusts(&mut self, rng: &mut impl rand::Rng) {
+        self.gust_model.update(rng);
+        self.wind.gust_speed_kmh = self.gust_model.current_speed();
     }
 
-    pub fn update_wind(&mut self, speed_kmh: f32, direction_deg: f32) {
-        self.raindrop_system.set_wind(speed_kmh, direction_deg);
-        self.snow_system.set_wind(speed_kmh, direction_deg);
+    /// Takes the wind from a weather report. Reports carry m/s, the particle
+    /// systems work in km/h.
+    pub fn update_wind_from(&mut self, weather: &WeatherData) {
+        self.update_wind(
+            ms_to_kmh(weather.wind_speed) as f32,
+            weather.wind_direction as f32,
+            weather.wind_gusts.map(|g| ms_to_kmh(g) as f32),
+        );
     }
 
-    pub fn update_fog_intensity(&mut self, intensity: FogIntensity) {
-        self.fog_system.set_intensity(intensity);
+    pub fn wind(&self) -> Wind {
+        self.wind
     }
 
-    pub fn render_background(
-        &mut self,
-        renderer: &mut TerminalRenderer,
-        conditions: &WeatherConditions,
-        state: &AppState,
-        term_width: u16,
-        term_height: u16,
-        mut rng: &mut impl rand::Rng,
-    ) -> io::Result<()> {
-        // Calculate horizon_y early so it's available for all systems
-        let ground_height = WorldScene::GROUND_HEIGHT;
-        let horizon_y = term_height.saturating_sub(ground_height);
-
-        if !conditions.is_day {
-            self.star_system.update(term_width, term_height, &mut rng);
-            self.star_system.render(renderer)?;
-            self.moon_system.update(term_width, term_height);
-            self.moon_system.render(renderer)?;
-
-            if state.should_show_fireflies() {
-                self.firefly_system
-                    .update(term_width, term_height, horizon_y, &mut rng);
-                self.firefly_system.render(renderer)?;
-            }
-        }
-
-        if !conditions.is_raining
-            && !conditions.is_thunderstorm
-            && !conditions.is_snowing
-            && conditions.is_day
-        {
-            self.bird_system.update(term_width, term_height, &mut rng);
-            self.bird_system.render(renderer)?;
-        }
-
-        if state.should_show_sun()
-            && !conditions.is_raining
-            && !conditions.is_thunderstorm
-            && !conditions.is_snowing
-        {
-            let animation_y = if term_height > 20 { 3 } else { 2 };
-            self.animation_controller
-                .render_frame(renderer, &self.sunny_animation, animation_y)?;
-        }
-
-        if conditions.is_cloudy
-            || (!conditions.is_raining && !conditions.is_thunderstorm && !conditions.is_snowing)
-        {
-            let (is_clear, cloud_color) = if let Some(weather) = &state.current_weather {
-                match weather.condition {
-                    crate::weather::WeatherCondition::Clear => (true, Color::White),
-                    crate::weather::WeatherCondition::PartlyCloudy => (false, Color::Grey),
-                    _ => (false, Color::DarkGrey),
-                }
-            } else {
-                (false, Color::DarkGrey)
-            };
-
-            if conditions.is_cloudy || is_clear {
-                self.cloud_system.set_cloud_color(is_clear);
-                self.cloud_system
-                    .update(term_width, term_height, is_clear, cloud_color, &mut rng);
-                self.cloud_system.render(renderer)?;
-            }
-        }
-
-        if !conditions.is_raining
-            && !conditions.is_thunderstorm
-            && !conditions.is_snowing
-            && !conditions.is_foggy
-        {
-            self.airplane_system
-                .update(term_width, term_height, &mut rng);
-            self.airplane_system.render(renderer)?;
-        }
-
-        Ok(())
+    /// Columns where rain reached the ground during the last frame.
+    pub fn ground_impacts(&self) -> Vec<u16> {
+        self.layers.ground_impacts()
     }
 
-    pub fn render_chimney_smoke(
-        &mut self,
+    /// Advances every active effect by one step of `SIMULATION_STEP`.
+    pub fn update(&mut self, ctx: &mut FrameContext) {
+        self.layers.update(ctx);
+    }
+
+    /// Draws the layers that sit behind the scene: the sky and everything
+    /// flying through it.
+    pub fn render_background(
+        &self,
         renderer: &mut TerminalRenderer,
-        conditions: &WeatherConditions,
-        term_width: u16,
-        term_height: u16,
-        mut rng: &mut impl rand::Rng,
+        ctx: &FrameContext,
     ) -> io::Result<()> {
-        if conditions.is_raining || conditions.is_thunderstorm {
-            return Ok(());
-        }
-        let ground_height = WorldScene::GROUND_HEIGHT;
-        let horizon_y = term_height.saturating_sub(ground_height);
-        let house_width = House::WIDTH;
-        let house_height = House::HEIGHT;
-        let house_x = (term_width / 2).saturating_sub(house_width / 2);
-        let house_y = horizon_y.saturating_sub(house_height);
-        let chimney_x = house_x + House::CHIMNEY_X_OFFSET;
-        let chimney_y = house_y;
-
-        self.chimney_smoke.update(chimney_x, chimney_y, &mut rng);
-        self.chimney_smoke.render(renderer)?;
-
-        Ok(())
+        self.layers.render(renderer, ctx, Layer::is_behind_scene)
     }
 
+    /// Draws smoke, precipitation and fog over the scene.
     pub fn render_foreground(
-        &mut self,
+        &self,
         renderer: &mut TerminalRenderer,
-        conditions: &WeatherConditions,
-        term_width: u16,
-        term_height: u16,
-        mut rng: &mut impl rand::Rng,
+        ctx: &FrameContext,
     ) -> io::Result<()> {
-        if conditions.is_thunderstorm {
-            self.raindrop_system
-                .update(term_width, term_height, &mut rng);
-            self.raindrop_system.render(renderer)?;
-
-            self.thunderstorm_system
-                .update(term_width, term_height, &mut rng);
-            self.thunderstorm_system.render(renderer)?;
-
-            if self.thunderstorm_system.is_flashing() {
-                renderer.flash_screen()?;
-            }
-        } else if conditions.is_raining {
-            self.raindrop_system
-                .update(term_width, term_height, &mut rng);
-            self.raindrop_system.render(renderer)?;
-        } else if conditions.is_snowing {
-            self.snow_system.update(term_width, term_height, &mut rng);
-            self.snow_system.render(renderer)?;
-        }
-
-        if conditions.is_foggy {
-            self.fog_system.update(term_width, term_height, &mut rng);
-            self.fog_system.render(renderer)?;
-        }
-
-        if self.show_leaves
-            && !conditions.is_raining
-            && !conditions.is_thunderstorm
-            && !conditions.is_snowing
-        {
-            self.falling_leaves
-                .update(term_width, term_height, &mut rng);
-            self.falling_leaves.render(renderer)?;
-        }
-
-        Ok(())
+        self.layers
+            .render(renderer, ctx, |layer| !layer.is_behind_scene())
     }
+}
 
-    pub fn update_sunny_animation(&mut self, conditions: &WeatherConditions) {
-        if !conditions.is_raining
-            && !conditions.is_thunderstorm
-            && !conditions.is_snowing
-            && self.last_frame_time.elapsed() >= FRAME_DELAY
-        {
-            self.animation_controller.next_frame(&self.sunny_animation);
-            self.last_frame_time = Instant::now();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weather::WeatherCondition;
+    use crate::weather::units::kmh_to_ms;
+
+    fn report(wind_kmh: f64) -> WeatherData {
+        WeatherData {
+            condition: WeatherCondition::Clear,
+            temperature: 20.0,
+            apparent_temperature: 20.0,
+            humidity: 50.0,
+            precipitation: 0.0,
+            wind_speed: kmh_to_ms(wind_kmh),
+            wind_direction: 270.0,
+            wind_gusts: Some(kmh_to_ms(wind_kmh + 20.0)),
+            cloud_cover: 0.0,
+            pressure: 1013.0,
+            visibility: None,
+            is_day: true,
+            moon_phase: None,
+            timestamp: "2024-01-01T12:00".to_string(),
         }
     }
+
+    #[test]
+    fn test_report_wind_reaches_birds_in_kmh() {
+        let mut manager = AnimationManager::new(80, 24, &AnimationsConfig::default());
+        let mut birds = BirdSystem::new(80, 24, 1.0);
+
+        manager.update_wind_from(&report(45.0));
+        let wind = manager.wind();
+        assert!((wind.speed_kmh - 45.0).abs() < 0.01);
+        birds.set_wind(wind.speed_kmh, wind.direction_deg);
+        assert!(birds.is_grounded());
+
+        manager.update_wind_from(&report(20.0));
+        let wind = manager.wind();
+        birds.set_wind(wind.speed_kmh, wind.direction_deg);
+        assert!(!birds.is_grounded());
+    }
 }
@@ -1,57 +1,176 @@
+use crate::animation::clock::FixedTimestep;
+use crate::animation::{FrameContext, Wind};
 use crate::animation_manager::AnimationManager;
-use crate::app_state::AppState;
-use crate::config::Config;
-use crate::error::WeatherError;
+use crate::app_state::{AppState, RetryStatus};
+use crate::cache;
+use crate::config::{Config, EventConfig, HttpConfig, Privacy};
+use crate::error::{ConfigError, WeatherError};
+use crate::error_history::ErrorHistory;
+use crate::geolocation::GeoLocation;
+use crate::geolocation::gpsd::GpsdWatcher;
 use crate::render::TerminalRenderer;
 use crate::scene::WorldScene;
+use crate::scene::events::EventCalendar;
+use crate::scene::season::Season;
+use crate::scene::themes::SceneKind;
+use crate::signals::{SignalAction, Signals};
+use crate::weather::backoff::Backoff;
+use crate::weather::units::{kmh_to_ms, ms_to_kmh};
 use crate::weather::{
-    OpenMeteoProvider, WeatherClient, WeatherCondition, WeatherData, WeatherLocation,
+    OpenMeteoProvider, WeatherClient, WeatherCondition, WeatherData, WeatherLocation, WeatherUnits,
 };
-use crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use crossterm::event::{Event, EventStream, KeyCode, KeyModifiers};
+use futures_util::StreamExt;
 use std::io;
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::mpsc;
+use std::time::{Duration, Instant, SystemTime};
+use tokio::sync::{Notify, mpsc};
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
 
 const REFRESH_INTERVAL: Duration = Duration::from_secs(300);
-const INPUT_POLL_FPS: u64 = 30;
-const FRAME_DURATION: Duration = Duration::from_millis(1000 / INPUT_POLL_FPS);
-
-fn generate_offline_weather(rng: &mut impl rand::Rng) -> WeatherData {
-    use chrono::{Local, Timelike};
-    use rand::RngExt;
-
-    let now = Local::now();
-    let hour = now.hour();
-    let is_day = (6..18).contains(&hour);
-
-    let conditions = [
-        WeatherCondition::Clear,
-        WeatherCondition::PartlyCloudy,
-        WeatherCondition::Cloudy,
-        WeatherCondition::Rain,
-    ];
-
-    let condition = conditions[rng.random_range(0..conditions.len())];
-
-    WeatherData {
-        condition,
-        temperature: rng.random_range(10.0..25.0),
-        apparent_temperature: rng.random_range(10.0..25.0),
-        humidity: rng.random_range(40.0..80.0),
-        precipitation: if condition.is_raining() {
-            rng.random_range(1.0..5.0)
-        } else {
-            0.0
-        },
-        wind_speed: rng.random_range(5.0..15.0),
-        wind_direction: rng.random_range(0.0..360.0),
-        cloud_cover: rng.random_range(20.0..80.0),
-        pressure: rng.random_range(1000.0..1020.0),
-        visibility: Some(10000.0),
-        is_day,
-        moon_phase: Some(0.5),
-        timestamp: now.format("%Y-%m-%dT%H:%M:%S").to_string(),
+/// Wait before the first retry of a failed fetch, doubled on every failure
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(5);
+pub const DEFAULT_FPS: u32 = 30;
+
+/// Result of one fetch, sent from the fetch task to the app.
+struct FetchOutcome {
+    /// The report and when the provider sent it
+    result: Result<(WeatherData, SystemTime), WeatherError>,
+    /// When the next attempt is due if this one failed and will be retried
+    retry: Option<RetryStatus>,
+}
+
+/// Background task that fetches the weather every `REFRESH_INTERVAL`.
+/// Dropping it stops the task.
+struct WeatherFetcher {
+    task: JoinHandle<()>,
+    refresh: Arc<Notify>,
+}
+
+impl WeatherFetcher {
+    fn spawn(
+        location: WeatherLocation,
+        units: WeatherUnits,
+        http: &HttpConfig,
+        tx: mpsc::Sender<FetchOutcome>,
+    ) -> Self {
+        let provider = OpenMeteoProvider::with_http_config(http);
+        let refresh = Arc::new(Notify::new());
+        let refresh_requested = Arc::clone(&refresh);
+
+        let task = tokio::spawn(async move {
+            // A broken [http] section will not fix itself, so report it once
+            // and wait for the config to be reloaded
+            let provider = match provider {
+                Ok(provider) => provider,
+                Err(e) => {
+                    let result = Err(WeatherError::Network(e));
+                    let _ = tx
+                        .send(FetchOutcome {
+                            result,
+                            retry: None,
+                        })
+                        .await;
+                    return;
+                }
+            };
+            let weather_client = WeatherClient::new(Arc::new(provider), REFRESH_INTERVAL);
+            let mut backoff = Backoff::new(RETRY_BASE_DELAY, REFRESH_INTERVAL);
+            loop {
+                let result = weather_client
+                    .get_current_weather_with_time(&location, &units)
+                    .await;
+
+                let should_retry = matches!(
+                    result,
+                    Err(WeatherError::Network(ref net_err)) if net_err.is_retryable()
+                );
+                let retry_after = match &result {
+                    Err(WeatherError::Network(net_err)) => net_err.retry_after(),
+                    _ => None,
+                };
+                let (wait, retry) = if should_retry {
+                    let wait = retry_delay(backoff.next_delay(&mut rand::rng()), retry_after);
+                    let retry = Instant::now().checked_add(wait).map(|at| RetryStatus {
+                        attempt: backoff.attempt(),
+                        at,
+                    });
+                    (wait, retry)
+                } else {
+                    backoff.reset();
+                    (REFRESH_INTERVAL, None)
+                };
+
+                if tx.send(FetchOutcome { result, retry }).await.is_err() {
+                    break;
+                }
+                tokio::select! {
+                    _ = tokio::time::sleep(wait) => {}
+                    _ = refresh_requested.notified() => weather_client.invalidate_cache().await,
+                }
+            }
+        });
+
+        Self { task, refresh }
+    }
+
+    /// Skips the rest of the wait and fetches fresh data from the provider.
+    fn refresh(&self) {
+        self.refresh.notify_one();
+    }
+}
+
+/// Wait before retrying a failed fetch. The server's Retry-After wins over a
+/// shorter backoff, but nothing waits longer than a regular refresh.
+fn retry_delay(backoff: Duration, retry_after: Option<Duration>) -> Duration {
+    backoff
+        .max(retry_after.unwrap_or_default())
+        .min(REFRESH_INTERVAL)
+}
+
+impl Drop for WeatherFetcher {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+type ConfigLoader = Box<dyn Fn() -> Result<Config, ConfigError>>;
+
+/// The parts of the config the scene is built from.
+#[derive(Debug, Clone, PartialEq)]
+struct SceneSettings {
+    kind: SceneKind,
+    /// Pinned season, `None` to follow the date
+    season: Option<Season>,
+    hide_holidays: bool,
+    events: Vec<EventConfig>,
+}
+
+impl SceneSettings {
+    fn from_config(config: &Config) -> Self {
+        Self {
+            kind: config.scene.clone(),
+            season: config.season,
+            hide_holidays: config.hide_holidays,
+            events: config.events.clone(),
+        }
+    }
+
+    /// Season to draw on `date` at `latitude`.
+    fn season_on(&self, date: chrono::NaiveDate, latitude: f64) -> Season {
+        self.season
+            .unwrap_or_else(|| Season::from_date(date, latitude))
+    }
+
+    fn build(&self, season: Season, width: u16, height: u16) -> Result<WorldScene, ConfigError> {
+        let calendar = EventCalendar::from_events(!self.hide_holidays, &self.events)?;
+        Ok(WorldScene::new(
+            width,
+            height,
+            self.kind.build(season)?,
+            calendar,
+        ))
     }
 }
 
@@ -59,8 +178,56 @@ pub struct App {
     state: AppState,
     animations: AnimationManager,
     scene: WorldScene,
-    weather_receiver: mpsc::Receiver<Result<WeatherData, WeatherError>>,
+    scene_settings: SceneSettings,
+    /// Season the scene was built for
+    season: Season,
+    weather_receiver: mpsc::Receiver<FetchOutcome>,
+    /// `None` while simulating a condition or in strict privacy mode
+    fetcher: Option<WeatherFetcher>,
+    simulating: bool,
+    /// Position updates from gpsd, when `[location.gpsd]` is enabled
+    gps: Option<GpsdWatcher>,
+    config_loader: Option<ConfigLoader>,
     hide_hud: bool,
+    errors: ErrorHistory,
+    show_errors: bool,
+    http: HttpConfig,
+    privacy: Privacy,
+    frame_duration: Duration,
+    clock: FixedTimestep,
+    last_frame: Instant,
+}
+
+/// Waits for the next GPS position, or forever when gpsd is not in use.
+async fn next_position(gps: &mut Option<GpsdWatcher>) -> Option<GeoLocation> {
+    match gps {
+        Some(watcher) => watcher.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Builds the context the particle systems see for one simulation step or
+/// one drawn frame.
+fn frame_context<'a>(
+    scene: &'a WorldScene,
+    state: &'a AppState,
+    wind: Wind,
+    (width, height): (u16, u16),
+    rng: &'a mut dyn rand::Rng,
+) -> FrameContext<'a> {
+    let anchors = scene.anchors();
+    FrameContext {
+        width,
+        height,
+        horizon_y: anchors.horizon_y,
+        emitters: &anchors.emitters,
+        mask: scene.collision_mask(),
+        conditions: &state.weather_conditions,
+        weather: state.current_weather.as_ref(),
+        holidays: scene.holidays(),
+        wind,
+        rng,
+    }
 }
 
 impl App {
@@ -68,9 +235,10 @@ impl App {
         config: &Config,
         simulate_condition: Option<String>,
         simulate_night: bool,
-        show_leaves: bool,
+        scene: WorldScene,
         term_width: u16,
         term_height: u16,
+        fps: u32,
     ) -> Self {
         let location = WeatherLocation {
             latitude: config.location.latitude,
@@ -79,17 +247,17 @@ impl App {
         };
 
         let mut state = AppState::new(location, config.location.hide, config.units);
-        let mut animations = AnimationManager::new(term_width, term_height, show_leaves);
-        let scene = WorldScene::new(term_width, term_height);
+        state.city = config.location.city.clone();
+        let mut animations = AnimationManager::new(term_width, term_height, &config.animations);
 
         let (tx, rx) = mpsc::channel(1);
 
-        if let Some(ref condition_str) = simulate_condition {
+        let fetcher = if let Some(ref condition_str) = simulate_condition {
             let simulated_condition =
                 condition_str
                     .parse::<WeatherCondition>()
                     .unwrap_or_else(|e| {
-                        eprintln!("{}", e);
+                        tracing::warn!("{}", e);
                         WeatherCondition::Clear
                     });
 
@@ -104,11 +272,16 @@ impl App {
                     0.0
                 },
                 wind_speed: if simulated_condition.is_thunderstorm() {
-                    45.0
+                    kmh_to_ms(45.0)
                 } else {
-                    10.0
+                    kmh_to_ms(10.0)
                 },
                 wind_direction: 225.0,
+                wind_gusts: Some(if simulated_condition.is_thunderstorm() {
+                    kmh_to_ms(80.0)
+                } else {
+                    kmh_to_ms(18.0)
+                }),
                 cloud_cover: 50.0,
                 pressure: 1013.0,
                 visibility: Some(10000.0),
@@ -117,173 +290,548 @@ impl App {
                 timestamp: "simulated".to_string(),
             };
 
-            let rain_intensity = weather.condition.rain_intensity();
-            let snow_intensity = weather.condition.snow_intensity();
-
-            let wind_speed = weather.wind_speed;
-            let wind_direction = weather.wind_direction;
-
+            animations.update_wind_from(&weather);
             state.update_weather(weather);
-            animations.update_rain_intensity(rain_intensity);
-            animations.update_snow_intensity(snow_intensity);
-            animations.update_wind(wind_speed as f32, wind_direction as f32);
+            None
+        } else if !config.privacy.allows_network() {
+            tracing::info!("strict privacy mode, weather comes from the cache only");
+            None
         } else {
-            let provider = Arc::new(OpenMeteoProvider::new());
-            let weather_client = WeatherClient::new(provider, REFRESH_INTERVAL);
-            let units = config.units;
-
-            tokio::spawn(async move {
-                loop {
-                    let result = weather_client.get_current_weather(&location, &units).await;
-                    if tx.send(result).await.is_err() {
-                        break;
-                    }
-                    tokio::time::sleep(REFRESH_INTERVAL).await;
-                }
-            });
-        }
+            Some(WeatherFetcher::spawn(
+                location,
+                config.units,
+                &config.http,
+                tx,
+            ))
+        };
+
+        let gps = (config.location.gpsd.enabled
+            && config.privacy.allows_gpsd(&config.location.gpsd)
+            && simulate_condition.is_none())
+        .then(|| {
+            let start = GeoLocation {
+                latitude: location.latitude,
+                longitude: location.longitude,
+                city: None,
+            };
+            GpsdWatcher::spawn(config.location.gpsd.clone(), start)
+        });
+
+        let scene_settings = SceneSettings::from_config(config);
+        let season = scene_settings.season_on(chrono::Local::now().date_naive(), location.latitude);
 
         Self {
             state,
             animations,
             scene,
+            scene_settings,
+            season,
             weather_receiver: rx,
+            fetcher,
+            simulating: simulate_condition.is_some(),
+            gps,
+            config_loader: None,
             hide_hud: config.hide_hud,
+            errors: ErrorHistory::new(),
+            show_errors: false,
+            http: config.http.clone(),
+            privacy: config.privacy,
+            frame_duration: Duration::from_secs(1) / fps.max(1),
+            clock: FixedTimestep::new(),
+            last_frame: Instant::now(),
         }
     }
 
-    pub async fn run(&mut self, renderer: &mut TerminalRenderer) -> io::Result<()> {
-        let mut rng = rand::rng();
-        loop {
-            if let Ok(result) = self.weather_receiver.try_recv() {
-                match result {
-                    Ok(weather) => {
-                        let rain_intensity = weather.condition.rain_intensity();
-                        let snow_intensity = weather.condition.snow_intensity();
-                        let fog_intensity = weather.condition.fog_intensity();
-                        let wind_speed = weather.wind_speed;
-                        let wind_direction = weather.wind_direction;
-
-                        self.state.update_weather(weather);
-                        self.animations.update_rain_intensity(rain_intensity);
-                        self.animations.update_snow_intensity(snow_intensity);
-                        self.animations.update_fog_intensity(fog_intensity);
-                        self.animations
-                            .update_wind(wind_speed as f32, wind_direction as f32);
-                    }
-                    Err(error) => {
-                        let _error_msg = match &error {
-                            WeatherError::Network(net_err) => net_err.user_friendly_message(),
-                            _ => format!("Failed to fetch weather: {}", error),
-                        };
-
-                        if self.state.current_weather.is_none() {
-                            let offline_weather = generate_offline_weather(&mut rng);
-                            let rain_intensity = offline_weather.condition.rain_intensity();
-                            let snow_intensity = offline_weather.condition.snow_intensity();
-                            let fog_intensity = offline_weather.condition.fog_intensity();
-                            let wind_speed = offline_weather.wind_speed;
-                            let wind_direction = offline_weather.wind_direction;
-
-                            self.state.update_weather(offline_weather);
-                            self.state.set_offline_mode(true);
-                            self.animations.update_rain_intensity(rain_intensity);
-                            self.animations.update_snow_intensity(snow_intensity);
-                            self.animations.update_fog_intensity(fog_intensity);
-                            self.animations
-                                .update_wind(wind_speed as f32, wind_direction as f32);
-                        } else {
-                            self.state.set_offline_mode(true);
-                        }
-                    }
-                }
+    /// Sets how the config is read again when the process receives SIGHUP.
+    pub fn set_config_loader(
+        &mut self,
+        loader: impl Fn() -> Result<Config, ConfigError> + 'static,
+    ) {
+        self.config_loader = Some(Box::new(loader));
+    }
+
+    /// Applies a freshly loaded config. The weather is fetched again if the
+    /// location or units changed. A config that fails to load is ignored and
+    /// the app keeps running with the old one.
+    fn reload_config(&mut self, (term_width, term_height): (u16, u16)) {
+        let Some(load) = &self.config_loader else {
+            return;
+        };
+        let mut config = match load() {
+            Ok(config) => config,
+            Err(e) => {
+                tracing::warn!(error = %e, "config reload failed, keeping the current config");
+                return;
             }
+        };
 
-            renderer.clear()?;
+        self.hide_hud = config.hide_hud;
+        self.state.hide_location = config.location.hide;
 
-            let (term_width, term_height) = renderer.get_size();
+        // A looked-up, detected or GPS location stays put, it was not read from the file
+        let location =
+            if config.location.auto || config.location.city.is_some() || self.gps.is_some() {
+                self.state.location
+            } else {
+                WeatherLocation {
+                    latitude: config.location.latitude,
+                    longitude: config.location.longitude,
+                    elevation: None,
+                }
+            };
+        if !self.simulating
+            && (location != self.state.location
+                || config.units != self.state.units
+                || config.http != self.http
+                || config.privacy != self.privacy)
+        {
+            self.state.location = location;
+            self.state.units = config.units;
+            self.http = config.http.clone();
+            self.privacy = config.privacy;
+            self.restart_fetcher();
+        }
 
-            self.animations.render_background(
-                renderer,
-                &self.state.weather_conditions,
-                &self.state,
-                term_width,
-                term_height,
-                &mut rng,
-            )?;
+        // The season follows the location in use, not the one in the file
+        let settings = SceneSettings::from_config(&config);
+        let season = settings.season_on(
+            chrono::Local::now().date_naive(),
+            self.state.location.latitude,
+        );
+        config.apply_season_defaults(season);
+        if settings != self.scene_settings || season != self.season {
+            self.rebuild_scene(settings, season, (term_width, term_height));
+        }
 
-            self.scene
-                .render(renderer, &self.state.weather_conditions)?;
+        let wind = self.animations.wind();
+        self.animations = AnimationManager::new(term_width, term_height, &config.animations);
+        let gusts = self
+            .state
+            .current_weather
+            .as_ref()
+            .and_then(|w| w.wind_gusts);
+        self.animations.update_wind(
+            wind.speed_kmh,
+            wind.direction_deg,
+            gusts.map(|g| ms_to_kmh(g) as f32),
+        );
+
+        self.state.weather_info_needs_update = true;
+    }
 
-            self.animations.render_chimney_smoke(
-                renderer,
-                &self.state.weather_conditions,
-                term_width,
-                term_height,
-                &mut rng,
-            )?;
+    /// Moves the scene to `date`. A session that runs into the next season
+    /// gets that season's art.
+    fn set_date(&mut self, date: chrono::NaiveDate, size: (u16, u16)) {
+        if !self.scene.set_date(date) {
+            return;
+        }
+        let season = self
+            .scene_settings
+            .season_on(date, self.state.location.latitude);
+        if season != self.season {
+            tracing::info!(?season, "season changed");
+            self.rebuild_scene(self.scene_settings.clone(), season, size);
+            self.scene.set_date(date);
+        }
+    }
 
-            self.animations.render_foreground(
-                renderer,
-                &self.state.weather_conditions,
-                term_width,
-                term_height,
-                &mut rng,
-            )?;
+    /// Builds the scene again for new settings or a new season. A scene or
+    /// events file that fails to load leaves the current scene in place
+    /// until the next reload.
+    fn rebuild_scene(
+        &mut self,
+        settings: SceneSettings,
+        season: Season,
+        (width, height): (u16, u16),
+    ) {
+        match settings.build(season, width, height) {
+            Ok(scene) => self.scene = scene,
+            Err(e) => tracing::warn!(error = %e, "scene reload failed, keeping the current scene"),
+        }
+        self.scene_settings = settings;
+        self.season = season;
+    }
+
+    /// Replaces the weather fetcher with one for the current location, units
+    /// and HTTP settings. In strict privacy mode there is none.
+    fn restart_fetcher(&mut self) {
+        if self.privacy.allows_network() {
+            let (tx, rx) = mpsc::channel(1);
+            self.fetcher = Some(WeatherFetcher::spawn(
+                self.state.location,
+                self.state.units,
+                &self.http,
+                tx,
+            ));
+            self.weather_receiver = rx;
+        } else {
+            self.fetcher = None;
+            self.state.set_offline_mode(true);
+        }
+    }
+
+    /// Moves to a new GPS position and fetches the weather there.
+    fn handle_position(&mut self, position: GeoLocation) {
+        self.state.location = WeatherLocation {
+            latitude: position.latitude,
+            longitude: position.longitude,
+            elevation: None,
+        };
+        self.state.city = None;
+        self.state.weather_info_needs_update = true;
+        if !self.simulating {
+            self.restart_fetcher();
+        }
+    }
+
+    /// Advances the scene and every particle system by one fixed step.
+    fn step(&mut self, size: (u16, u16), rng: &mut impl rand::Rng) {
+        let mut ctx = frame_context(&self.scene, &self.state, self.animations.wind(), size, rng);
+        self.animations.update(&mut ctx);
+
+        let precipitation = self
+            .state
+            .current_weather
+            .as_ref()
+            .map_or(0.0, |w| w.precipitation);
+        let conditions = &self.state.weather_conditions;
+        let impacts = if conditions.is_raining || conditions.is_thunderstorm {
+            self.animations.ground_impacts()
+        } else {
+            Vec::new()
+        };
+        self.scene.update(conditions, precipitation, &impacts);
 
-            self.state.update_loading_animation();
-            self.state.update_cached_info();
+        self.animations.update_gusts(rng);
+    }
 
-            if !self.hide_hud {
-                renderer.render_line_colored(
-                    2,
-                    1,
-                    &self.state.cached_weather_info,
-                    crossterm::style::Color::Cyan,
-                )?;
+    /// Applies a weather report. When the fetch failed the last known
+    /// observation is restored from the disk cache, however old.
+    async fn handle_weather(&mut self, outcome: FetchOutcome) {
+        self.state.set_retry(outcome.retry);
+
+        match outcome.result {
+            Ok((weather, observed_at)) => {
+                self.animations.update_wind_from(&weather);
+                let previous = self.state.current_weather.as_ref().map(|w| w.condition);
+                if previous != Some(weather.condition) {
+                    tracing::info!(
+                        from = ?previous,
+                        to = ?weather.condition,
+                        "weather condition changed"
+                    );
+                }
+
+                self.errors.dismiss_toast();
+                self.state.update_weather_observed(weather, observed_at);
             }
+            Err(error) => {
+                tracing::warn!(kind = error.kind(), error = %error, "weather fetch failed");
+                self.errors.push(&error);
 
-            let attribution = "Weather data by Open-Meteo.com";
-            let attribution_x = if term_width > attribution.len() as u16 {
-                term_width - attribution.len() as u16 - 2
-            } else {
-                0
-            };
-            let attribution_y = if term_height > 0 { term_height - 1 } else { 0 };
+                if self.state.current_weather.is_none() {
+                    self.restore_last_known().await;
+                } else {
+                    self.state.set_offline_mode(true);
+                }
+            }
+        }
+    }
+
+    /// Shows the last weather saved for this location, or a placeholder
+    /// scene when there is none.
+    async fn restore_last_known(&mut self) {
+        let location = self.state.location;
+        match cache::load_last_known_weather(location.latitude, location.longitude).await {
+            Some((weather, observed_at)) => {
+                self.animations.update_wind_from(&weather);
+                self.state.restore_weather(weather, observed_at);
+            }
+            None => {
+                use chrono::Timelike;
+                let hour = chrono::Local::now().hour();
+                self.state.show_placeholder((6..18).contains(&hour));
+            }
+        }
+    }
+
+    /// Handles a terminal event. Returns `false` once the user asks to quit.
+    fn handle_event(&mut self, renderer: &mut TerminalRenderer, event: Event) -> io::Result<bool> {
+        match event {
+            Event::Resize(width, height) => {
+                renderer.manual_resize(width, height)?;
+                self.scene.update_size(width, height);
+            }
+            Event::Key(key_event) => match key_event.code {
+                KeyCode::Char('q') | KeyCode::Char('Q') => return Ok(false),
+                KeyCode::Char('e') | KeyCode::Char('E') => self.show_errors = !self.show_errors,
+                KeyCode::Esc => self.show_errors = false,
+                KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Ok(false);
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    /// Catches the simulation up with the wall clock and draws one frame.
+    fn render_frame(
+        &mut self,
+        renderer: &mut TerminalRenderer,
+        rng: &mut impl rand::Rng,
+    ) -> io::Result<()> {
+        let frame_start = Instant::now();
+        renderer.clear()?;
+
+        let (term_width, term_height) = renderer.get_size();
+
+        // The systems only ever advance in whole fixed steps, so a late or
+        // early frame changes how many steps run, not how far each one goes.
+        let steps = self
+            .clock
+            .advance(frame_start.duration_since(self.last_frame));
+        self.last_frame = frame_start;
+        for _ in 0..steps {
+            self.step((term_width, term_height), rng);
+        }
+        self.set_date(chrono::Local::now().date_naive(), (term_width, term_height));
+
+        let ctx = frame_context(
+            &self.scene,
+            &self.state,
+            self.animations.wind(),
+            (term_width, term_height),
+            rng,
+        );
+
+        self.animations.render_background(renderer, &ctx)?;
+
+        self.scene.render(
+            renderer,
+            &self.state.weather_conditions,
+            &self.state.wind_conditions(),
+        )?;
+
+        self.animations.render_foreground(renderer, &ctx)?;
+
+        self.state.update_loading_animation();
+        self.state.update_retry_countdown();
+        self.state.update_cached_info();
+
+        if !self.hide_hud {
             renderer.render_line_colored(
-                attribution_x,
-                attribution_y,
-                attribution,
-                crossterm::style::Color::DarkGrey,
+                2,
+                1,
+                &self.state.cached_weather_info,
+                crossterm::style::Color::Cyan,
             )?;
+        }
+
+        let attribution = "Weather data by Open-Meteo.com";
+        let attribution_x = if term_width > attribution.len() as u16 {
+            term_width - attribution.len() as u16 - 2
+        } else {
+            0
+        };
+        let attribution_y = if term_height > 0 { term_height - 1 } else { 0 };
+        renderer.render_line_colored(
+            attribution_x,
+            attribution_y,
+            attribution,
+            crossterm::style::Color::DarkGrey,
+        )?;
+
+        if !self.hide_hud
+            && let Some(toast) = self.errors.toast()
+        {
+            renderer.render_toast(&toast, crossterm::style::Color::Yellow)?;
+        }
 
-            renderer.flush()?;
+        if self.show_errors {
+            renderer.render_overlay(
+                "Recent errors (e to close)",
+                &self.errors.overlay_lines(),
+                crossterm::style::Color::White,
+            )?;
+        }
 
-            if event::poll(FRAME_DURATION)? {
-                match event::read()? {
-                    Event::Resize(width, height) => {
-                        renderer.manual_resize(width, height)?;
-                    }
-                    Event::Key(key_event) => match key_event.code {
-                        KeyCode::Char('q') | KeyCode::Char('Q') => break,
-                        KeyCode::Char('c')
-                            if key_event.modifiers.contains(KeyModifiers::CONTROL) =>
-                        {
+        renderer.flush()
+    }
+
+    pub async fn run(&mut self, renderer: &mut TerminalRenderer) -> io::Result<()> {
+        let mut rng = rand::rng();
+        let mut events = EventStream::new();
+        let mut ticker = tokio::time::interval(self.frame_duration);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        let mut signals = Signals::new()?;
+
+        // Nothing will arrive from the network, so start from the cache
+        if self.fetcher.is_none() && self.state.current_weather.is_none() {
+            self.restore_last_known().await;
+        }
+
+        self.last_frame = Instant::now();
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => self.render_frame(renderer, &mut rng)?,
+                Some(outcome) = self.weather_receiver.recv() => {
+                    self.handle_weather(outcome).await;
+                }
+                Some(position) = next_position(&mut self.gps) => self.handle_position(position),
+                event = events.next() => match event {
+                    Some(Ok(event)) => {
+                        if !self.handle_event(renderer, event)? {
                             break;
                         }
-                        _ => {}
-                    },
-                    _ => {}
-                }
+                    }
+                    Some(Err(e)) => return Err(e),
+                    None => break,
+                },
+                action = signals.recv() => match action {
+                    SignalAction::Shutdown => {
+                        tracing::info!("shutting down on signal");
+                        break;
+                    }
+                    SignalAction::Reload => {
+                        tracing::info!("reloading config on SIGHUP");
+                        self.reload_config(renderer.get_size());
+                    }
+                    SignalAction::Refresh => {
+                        tracing::info!("refreshing weather on SIGUSR1");
+                        if let Some(fetcher) = &self.fetcher {
+                            fetcher.refresh();
+                        }
+                    }
+                },
             }
+        }
 
-            let (term_width, term_height) = renderer.get_size();
-            self.scene.update_size(term_width, term_height);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::GeolocationError;
+    use crate::geolocation;
+    use crate::http;
+    use crate::scene::themes::cottage::CottageTheme;
+
+    #[tokio::test]
+    async fn test_strict_privacy_never_creates_a_client() {
+        let config = Config {
+            privacy: Privacy::Strict,
+            ..Config::default()
+        };
+        let theme = Box::new(CottageTheme::new(Season::Summer));
+        let scene = WorldScene::new(80, 24, theme, EventCalendar::new(false, Vec::new()));
 
-            self.animations
-                .update_sunny_animation(&self.state.weather_conditions);
+        let app = App::new(&config, None, false, scene, 80, 24, DEFAULT_FPS);
+        assert!(app.fetcher.is_none());
+
+        // A location cached earlier may be used, anything else is refused
+        if let Err(error) = geolocation::detect_location(&config).await {
+            assert!(matches!(error, GeolocationError::NetworkDisabled));
         }
 
-        Ok(())
+        assert_eq!(http::clients_created(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_strict_privacy_skips_remote_gpsd() {
+        let mut config = Config {
+            privacy: Privacy::Strict,
+            ..Config::default()
+        };
+        config.location.gpsd.enabled = true;
+        config.location.gpsd.host = "192.168.1.20".to_string();
+        let scene = || {
+            let theme = Box::new(CottageTheme::new(Season::Summer));
+            WorldScene::new(80, 24, theme, EventCalendar::new(false, Vec::new()))
+        };
+
+        let app = App::new(&config, None, false, scene(), 80, 24, DEFAULT_FPS);
+        assert!(app.gps.is_none());
+
+        config.location.gpsd.host = "localhost".to_string();
+        let app = App::new(&config, None, false, scene(), 80, 24, DEFAULT_FPS);
+        assert!(app.gps.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_reload_rebuilds_scene_for_location_in_use() {
+        let config = Config {
+            privacy: Privacy::Strict,
+            ..Config::default()
+        };
+        let theme = Box::new(CottageTheme::new(Season::Summer));
+        let scene = WorldScene::new(80, 24, theme, EventCalendar::new(false, Vec::new()));
+        let mut app = App::new(&config, None, false, scene, 80, 24, DEFAULT_FPS);
+
+        // Looked up with --city, the file still holds the northern default
+        app.state.location.latitude = -33.87;
+        app.set_config_loader(|| {
+            let mut config = Config {
+                privacy: Privacy::Strict,
+                scene: SceneKind::City,
+                hide_holidays: true,
+                ..Config::default()
+            };
+            config.location.city = Some("Sydney".to_string());
+            Ok(config)
+        });
+        app.reload_config((80, 24));
+
+        let today = chrono::Local::now().date_naive();
+        assert_eq!(app.season, Season::from_date(today, -33.87));
+        assert_ne!(
+            app.season,
+            Season::from_date(today, config.location.latitude)
+        );
+        assert_eq!(app.scene_settings.kind, SceneKind::City);
+        assert!(app.scene_settings.hide_holidays);
+        assert_eq!(app.state.location.latitude, -33.87);
+    }
+
+    #[tokio::test]
+    async fn test_scene_follows_season_change() {
+        let config = Config {
+            privacy: Privacy::Strict,
+            ..Config::default()
+        };
+        let theme = Box::new(CottageTheme::new(Season::Summer));
+        let scene = WorldScene::new(80, 24, theme, EventCalendar::new(false, Vec::new()));
+        let mut app = App::new(&config, None, false, scene, 80, 24, DEFAULT_FPS);
+        app.state.location.latitude = 52.52;
+
+        let date = |month, day| chrono::NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+        app.set_date(date(8, 31), (80, 24));
+        assert_eq!(app.season, Season::Summer);
+        app.set_date(date(9, 1), (80, 24));
+        assert_eq!(app.season, Season::Autumn);
+
+        // A pinned season stays put
+        app.scene_settings.season = Some(Season::Winter);
+        app.season = Season::Winter;
+        app.set_date(date(3, 1), (80, 24));
+        assert_eq!(app.season, Season::Winter);
+    }
+
+    #[test]
+    fn test_retry_delay_is_capped() {
+        let backoff = Duration::from_secs(5);
+        assert_eq!(retry_delay(backoff, None), backoff);
+        assert_eq!(
+            retry_delay(backoff, Some(Duration::from_secs(60))),
+            Duration::from_secs(60)
+        );
+
+        let huge = retry_delay(backoff, Some(Duration::from_secs(u64::MAX)));
+        assert_eq!(huge, REFRESH_INTERVAL);
+        assert!(Instant::now().checked_add(huge).is_some());
     }
 }
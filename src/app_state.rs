@@ -1,18 +1,26 @@
 use crate::weather::{
     WeatherCondition, WeatherConditions, WeatherData, WeatherLocation, WeatherUnits,
-    format_precipitation, format_temperature, format_wind_speed,
+    WindConditions, format_precipitation, format_temperature, format_wind_speed,
 };
-use std::time::Instant;
+use std::time::{Duration, Instant, SystemTime};
 
 pub struct AppState {
     pub current_weather: Option<WeatherData>,
+    /// When `current_weather` was fetched from the provider
+    pub observed_at: Option<SystemTime>,
     pub is_offline: bool,
+    /// Set while failed fetches are being retried
+    pub retry: Option<RetryStatus>,
+    /// Seconds until the retry as last shown in the HUD
+    shown_retry_secs: Option<u64>,
     pub weather_conditions: WeatherConditions,
     pub loading_state: LoadingState,
     pub cached_weather_info: String,
     pub weather_info_needs_update: bool,
     pub location: WeatherLocation,
     pub hide_location: bool,
+    /// Shown instead of the coordinates when the location came from a city name
+    pub city: Option<String>,
     pub units: WeatherUnits,
 }
 
@@ -20,18 +28,28 @@ impl AppState {
     pub fn new(location: WeatherLocation, hide_location: bool, units: WeatherUnits) -> Self {
         Self {
             current_weather: None,
+            observed_at: None,
             is_offline: false,
+            retry: None,
+            shown_retry_secs: None,
             weather_conditions: WeatherConditions::default(),
             loading_state: LoadingState::new(),
             cached_weather_info: String::new(),
             weather_info_needs_update: true,
             location,
             hide_location,
+            city: None,
             units,
         }
     }
 
     pub fn update_weather(&mut self, weather: WeatherData) {
+        self.update_weather_observed(weather, SystemTime::now());
+    }
+
+    /// Shows a report the provider sent at `observed_at`, which is earlier
+    /// than now when it was served from a cache.
+    pub fn update_weather_observed(&mut self, weather: WeatherData, observed_at: SystemTime) {
         self.weather_conditions.is_thunderstorm = weather.condition.is_thunderstorm();
         self.weather_conditions.is_snowing = weather.condition.is_snowing();
         self.weather_conditions.is_raining =
@@ -41,15 +59,46 @@ impl AppState {
         self.weather_conditions.is_day = weather.is_day;
 
         self.current_weather = Some(weather);
+        self.observed_at = Some(observed_at);
         self.is_offline = false;
         self.weather_info_needs_update = true;
     }
 
+    /// Shows an older observation from the cache while the provider is unreachable.
+    pub fn restore_weather(&mut self, weather: WeatherData, observed_at: SystemTime) {
+        self.update_weather_observed(weather, observed_at);
+        self.set_offline_mode(true);
+    }
+
+    /// Draws a calm overcast sky when there is no weather at all to show.
+    pub fn show_placeholder(&mut self, is_day: bool) {
+        self.weather_conditions = WeatherConditions {
+            is_cloudy: true,
+            is_day,
+            ..WeatherConditions::default()
+        };
+        self.set_offline_mode(true);
+    }
+
     pub fn set_offline_mode(&mut self, offline: bool) {
         self.is_offline = offline;
         self.weather_info_needs_update = true;
     }
 
+    pub fn set_retry(&mut self, retry: Option<RetryStatus>) {
+        self.retry = retry;
+        self.weather_info_needs_update = true;
+    }
+
+    /// Refreshes the HUD whenever the retry countdown ticks over a second.
+    pub fn update_retry_countdown(&mut self) {
+        let secs = self.retry.map(|retry| retry.remaining_secs());
+        if secs != self.shown_retry_secs {
+            self.shown_retry_secs = secs;
+            self.weather_info_needs_update = true;
+        }
+    }
+
     pub fn update_loading_animation(&mut self) {
         if self.loading_state.should_update() {
             self.loading_state.next_frame();
@@ -87,6 +136,8 @@ impl AppState {
 
         let location_str = if self.hide_location {
             String::new()
+        } else if let Some(city) = &self.city {
+            format!(" | Location: {city}")
         } else {
             let (lat_value, lat_dir) = if self.location.latitude >= 0.0 {
                 (self.location.latitude, "N")
@@ -104,26 +155,55 @@ impl AppState {
             )
         };
 
+        let retry_str = match self.retry {
+            Some(retry) => format!(
+                "retrying in {}s (attempt {}) | ",
+                retry.remaining_secs(),
+                retry.attempt
+            ),
+            None => String::new(),
+        };
+
         self.cached_weather_info = if let Some(ref weather) = self.current_weather {
             let (temp, temp_unit) = format_temperature(weather.temperature, self.units.temperature);
             let (wind, wind_unit) = format_wind_speed(weather.wind_speed, self.units.wind_speed);
+            let wind_str = match weather.wind_gusts {
+                Some(gusts) if gusts > weather.wind_speed => {
+                    let (gusts, _) = format_wind_speed(gusts, self.units.wind_speed);
+                    format!("{:.0} (gusts {:.0}) {}", wind, gusts, wind_unit)
+                }
+                _ => format!("{:.1}{}", wind, wind_unit),
+            };
             let (precip, precip_unit) =
                 format_precipitation(weather.precipitation, self.units.precipitation);
 
-            let offline_indicator = if self.is_offline { "OFFLINE | " } else { "" };
+            let offline_indicator = match self.observed_at {
+                Some(observed_at) if self.is_offline => {
+                    let age = SystemTime::now()
+                        .duration_since(observed_at)
+                        .unwrap_or_default();
+                    format!("OFFLINE, data from {} | {}", format_age(age), retry_str)
+                }
+                _ if self.is_offline => format!("OFFLINE | {}", retry_str),
+                _ => String::new(),
+            };
 
             format!(
-                "{}Weather: {} | Temp: {:.1}{} | Wind: {:.1}{} | Precip: {:.1}{}{} | Press 'q' to quit",
+                "{}Weather: {} | Temp: {:.1}{} | Wind: {} | Precip: {:.1}{}{} | Press 'q' to quit",
                 offline_indicator,
                 self.get_condition_text(),
                 temp,
                 temp_unit,
-                wind,
-                wind_unit,
+                wind_str,
                 precip,
                 precip_unit,
                 location_str
             )
+        } else if self.is_offline {
+            format!(
+                "OFFLINE | {}Weather: No data yet | Press 'q' to quit{}",
+                retry_str, location_str
+            )
         } else {
             format!("Weather: Loading... {}", self.loading_state.current_char())
         };
@@ -131,40 +211,42 @@ impl AppState {
         self.weather_info_needs_update = false;
     }
 
-    pub fn should_show_sun(&self) -> bool {
-        if !self.weather_conditions.is_day {
-            return false;
-        }
-
-        if let Some(ref weather) = self.current_weather {
-            matches!(
-                weather.condition,
-                WeatherCondition::Clear | WeatherCondition::PartlyCloudy | WeatherCondition::Cloudy
-            )
-        } else {
-            false
-        }
+    pub fn wind_conditions(&self) -> WindConditions {
+        self.current_weather
+            .as_ref()
+            .map(|weather| WindConditions {
+                speed: weather.wind_speed,
+                direction: weather.wind_direction,
+            })
+            .unwrap_or_default()
     }
+}
 
-    pub fn should_show_fireflies(&self) -> bool {
-        if self.weather_conditions.is_day {
-            return false;
-        }
+/// When the weather fetch will be tried again after a failure.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryStatus {
+    /// Number of the upcoming attempt, counting the first fetch as 1
+    pub attempt: u32,
+    pub at: Instant,
+}
 
-        if let Some(ref weather) = self.current_weather {
-            let is_warm = weather.temperature > 15.0;
-            let is_clear_night = matches!(
-                weather.condition,
-                WeatherCondition::Clear | WeatherCondition::PartlyCloudy
-            );
-            is_warm
-                && is_clear_night
-                && !self.weather_conditions.is_raining
-                && !self.weather_conditions.is_thunderstorm
-                && !self.weather_conditions.is_snowing
-        } else {
-            false
-        }
+impl RetryStatus {
+    pub fn remaining_secs(&self) -> u64 {
+        self.at
+            .saturating_duration_since(Instant::now())
+            .as_secs_f64()
+            .ceil() as u64
+    }
+}
+
+/// Coarse age of an observation for the HUD, such as "3h ago".
+fn format_age(age: Duration) -> String {
+    let minutes = age.as_secs() / 60;
+    match minutes {
+        0 => "just now".to_string(),
+        1..60 => format!("{}m ago", minutes),
+        60..1440 => format!("{}h ago", minutes / 60),
+        _ => format!("{}d ago", minutes / 1440),
     }
 }
 
@@ -223,6 +305,7 @@ mod tests {
             precipitation: 0.0,
             wind_speed: 10.0,
             wind_direction: 0.0,
+            wind_gusts: None,
             cloud_cover: 0.0,
             pressure: 1013.0,
             visibility: Some(10.0),
@@ -290,6 +373,125 @@ mod tests {
         assert!(app.cached_weather_info.contains("139.65°E"));
     }
 
+    #[test]
+    fn test_wind_gusts_in_hud() {
+        let mut app = create_app_state(52.52, 13.41);
+        if let Some(weather) = app.current_weather.as_mut() {
+            weather.wind_speed = 5.0;
+            weather.wind_gusts = Some(12.5);
+        }
+        app.weather_info_needs_update = true;
+        app.update_cached_info();
+
+        assert!(app.cached_weather_info.contains("Wind: 18 (gusts 45) km/h"));
+    }
+
+    #[test]
+    fn test_offline_hud_shows_data_age() {
+        let mut app = create_app_state(52.52, 13.41);
+        let weather = app.current_weather.clone().unwrap();
+        let three_hours_ago = SystemTime::now() - Duration::from_secs(3 * 3600 + 60);
+        app.restore_weather(weather, three_hours_ago);
+        app.update_cached_info();
+
+        assert!(
+            app.cached_weather_info
+                .starts_with("OFFLINE, data from 3h ago | Weather: Clear")
+        );
+    }
+
+    #[test]
+    fn test_cached_report_keeps_its_observation_time() {
+        let mut app = create_app_state(52.52, 13.41);
+        let weather = app.current_weather.clone().unwrap();
+        let three_hours_ago = SystemTime::now() - Duration::from_secs(3 * 3600 + 60);
+        app.update_weather_observed(weather, three_hours_ago);
+        app.set_offline_mode(true);
+        app.update_cached_info();
+
+        assert!(
+            app.cached_weather_info
+                .starts_with("OFFLINE, data from 3h ago | Weather: Clear")
+        );
+    }
+
+    #[test]
+    fn test_offline_placeholder_without_history() {
+        let location = WeatherLocation {
+            latitude: 52.52,
+            longitude: 13.41,
+            elevation: None,
+        };
+        let mut app = AppState::new(location, true, WeatherUnits::default());
+        app.show_placeholder(true);
+        app.update_cached_info();
+
+        assert!(app.current_weather.is_none());
+        assert!(app.weather_conditions.is_cloudy && app.weather_conditions.is_day);
+        assert!(
+            app.cached_weather_info
+                .starts_with("OFFLINE | Weather: No data yet")
+        );
+    }
+
+    #[test]
+    fn test_retry_countdown_in_hud() {
+        let mut app = create_app_state(52.52, 13.41);
+        app.set_offline_mode(true);
+        app.set_retry(Some(RetryStatus {
+            attempt: 3,
+            at: Instant::now() + Duration::from_millis(19_500),
+        }));
+        app.update_retry_countdown();
+        app.update_cached_info();
+
+        assert!(
+            app.cached_weather_info
+                .contains("| retrying in 20s (attempt 3) | Weather: Clear")
+        );
+
+        app.update_retry_countdown();
+        assert!(!app.weather_info_needs_update);
+        app.set_retry(None);
+        app.update_cached_info();
+        assert!(!app.cached_weather_info.contains("retrying"));
+
+        // First run, nothing fetched or cached yet
+        app.current_weather = None;
+        app.set_retry(Some(RetryStatus {
+            attempt: 2,
+            at: Instant::now() + Duration::from_millis(9_500),
+        }));
+        app.update_cached_info();
+        assert!(
+            app.cached_weather_info
+                .starts_with("OFFLINE | retrying in 10s (attempt 2) | Weather: No data yet")
+        );
+    }
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(Duration::from_secs(30)), "just now");
+        assert_eq!(format_age(Duration::from_secs(25 * 60)), "25m ago");
+        assert_eq!(format_age(Duration::from_secs(3 * 3600)), "3h ago");
+        assert_eq!(format_age(Duration::from_secs(50 * 3600)), "2d ago");
+    }
+
+    #[test]
+    fn test_city_name_replaces_coordinates() {
+        let mut app = create_app_state(35.6895, 139.6917);
+        app.city = Some("Tokyo".to_string());
+        app.update_cached_info();
+
+        assert!(app.cached_weather_info.contains("Location: Tokyo"));
+        assert!(!app.cached_weather_info.contains("35.69°N"));
+
+        app.hide_location = true;
+        app.weather_info_needs_update = true;
+        app.update_cached_info();
+        assert!(!app.cached_weather_info.contains("Tokyo"));
+    }
+
     #[test]
     fn test_equator_prime_meridian() {
         // Null Island: 0°, 0° (exactly at equator and prime meridian)
@@ -1,7 +1,9 @@
 use crate::geolocation::GeoLocation;
 use crate::weather::WeatherData;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::fs;
 
 const LOCATION_CACHE_DURATION_SECS: u64 = 86400;
@@ -30,8 +32,8 @@ fn get_cache_dir() -> Option<PathBuf> {
 }
 
 fn current_timestamp() -> u64 {
-    std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
         .map(|d| d.as_secs())
         .unwrap_or(0)
 }
@@ -45,10 +47,12 @@ pub async fn load_cached_location() -> Option<GeoLocation> {
     let contents = fs::read_to_string(&cache_path).await.ok()?;
     let cache: LocationCache = serde_json::from_str(&contents).ok()?;
 
-    let now = current_timestamp();
-    if now - cache.cached_at < LOCATION_CACHE_DURATION_SECS {
+    let age_secs = current_timestamp().saturating_sub(cache.cached_at);
+    if age_secs < LOCATION_CACHE_DURATION_SECS {
+        tracing::debug!(age_secs, "location cache hit");
         Some(cache.location)
     } else {
+        tracing::debug!(age_secs, "location cache expired");
         None
     }
 }
@@ -71,24 +75,107 @@ pub fn save_location_cache(location: &GeoLocation) {
     });
 }
 
-pub async fn load_cached_weather(latitude: f64, longitude: f64) -> Option<WeatherData> {
+/// Key for a city name as typed in the config or on the command line.
+fn make_city_key(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+async fn read_city_cache() -> BTreeMap<String, LocationCache> {
+    let Some(cache_dir) = get_cache_dir() else {
+        return BTreeMap::new();
+    };
+    match fs::read_to_string(cache_dir.join("cities.json")).await {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => BTreeMap::new(),
+    }
+}
+
+/// The place a city name was resolved to before. Unlike the detected
+/// location this never expires: cities do not move, and the answer to an
+/// ambiguous name should not be asked for again.
+pub async fn load_cached_city(name: &str) -> Option<GeoLocation> {
+    let location = read_city_cache()
+        .await
+        .remove(&make_city_key(name))
+        .map(|cache| cache.location);
+    tracing::debug!(city = name, hit = location.is_some(), "city cache lookup");
+    location
+}
+
+pub fn save_city_cache(name: &str, location: &GeoLocation) {
+    let key = make_city_key(name);
+    let location = location.clone();
+    tokio::spawn(async move {
+        if let Some(cache_dir) = get_cache_dir() {
+            let _ = fs::create_dir_all(&cache_dir).await;
+
+            let mut cities = read_city_cache().await;
+            cities.insert(
+                key,
+                LocationCache {
+                    location,
+                    cached_at: current_timestamp(),
+                },
+            );
+
+            if let Ok(json) = serde_json::to_string(&cities) {
+                let _ = fs::write(cache_dir.join("cities.json"), json).await;
+            }
+        }
+    });
+}
+
+async fn read_weather_cache(latitude: f64, longitude: f64) -> Option<WeatherCache> {
     let cache_path = get_cache_dir()?.join("weather.json");
-    let contents = fs::read_to_string(&cache_path).await.ok()?;
+    let Ok(contents) = fs::read_to_string(&cache_path).await else {
+        tracing::debug!(path = %cache_path.display(), "no weather cache on disk");
+        return None;
+    };
     let cache: WeatherCache = serde_json::from_str(&contents).ok()?;
 
     let location_key = make_location_key(latitude, longitude);
     if cache.location_key != location_key {
+        tracing::debug!(
+            cached = %cache.location_key,
+            wanted = %location_key,
+            "weather cache is for another location"
+        );
         return None;
     }
+    Some(cache)
+}
 
-    let now = current_timestamp();
-    if now - cache.cached_at < WEATHER_CACHE_DURATION_SECS {
-        Some(cache.data)
+/// Weather saved for this location that is still fresh, with the time it
+/// was fetched.
+pub async fn load_cached_weather(
+    latitude: f64,
+    longitude: f64,
+) -> Option<(WeatherData, SystemTime)> {
+    let cache = read_weather_cache(latitude, longitude).await?;
+
+    let age_secs = current_timestamp().saturating_sub(cache.cached_at);
+    if age_secs < WEATHER_CACHE_DURATION_SECS {
+        tracing::debug!(age_secs, "disk weather cache hit");
+        let fetched_at = UNIX_EPOCH + Duration::from_secs(cache.cached_at);
+        Some((cache.data, fetched_at))
     } else {
+        tracing::debug!(age_secs, "disk weather cache expired");
         None
     }
 }
 
+/// Last weather saved for this location however old it is, with the time
+/// it was fetched. Used to show real, if stale, data while offline.
+pub async fn load_last_known_weather(
+    latitude: f64,
+    longitude: f64,
+) -> Option<(WeatherData, SystemTime)> {
+    let cache = read_weather_cache(latitude, longitude).await?;
+    tracing::info!(cached_at = cache.cached_at, "using last known weather");
+    let fetched_at = UNIX_EPOCH + Duration::from_secs(cache.cached_at);
+    Some((cache.data, fetched_at))
+}
+
 pub fn save_weather_cache(weather: &WeatherData, latitude: f64, longitude: f64) {
     let weather = weather.clone();
     tokio::spawn(async move {
@@ -102,7 +189,13 @@ pub fn save_weather_cache(weather: &WeatherData, latitude: f64, longitude: f64)
             };
 
             if let Ok(json) = serde_json::to_string(&cache) {
-                let _ = fs::write(cache_dir.join("weather.json"), json).await;
+                let path = cache_dir.join("weather.json");
+                match fs::write(&path, json).await {
+                    Ok(()) => tracing::debug!(path = %path.display(), "saved weather cache"),
+                    Err(e) => {
+                        tracing::warn!(path = %path.display(), error = %e, "cannot save weather cache")
+                    }
+                }
             }
         }
     });
@@ -1,4 +1,6 @@
+use chrono::{DateTime, Utc};
 use std::io;
+use std::time::Duration;
 use thiserror::Error as ThisError;
 
 #[derive(ThisError, Debug)]
@@ -16,11 +18,36 @@ pub enum WeatherError {
     Geolocation(#[from] GeolocationError),
 }
 
+impl WeatherError {
+    pub fn kind(&self) -> &str {
+        match self {
+            WeatherError::Network(e) => e.kind(),
+            WeatherError::Config(e) => e.kind(),
+            WeatherError::Terminal(_) => "Terminal",
+            WeatherError::Geolocation(_) => "Geolocation",
+        }
+    }
+
+    pub fn user_friendly_message(&self) -> String {
+        match self {
+            WeatherError::Network(e) => e.user_friendly_message(),
+            _ => format!("Failed to fetch weather: {}", self),
+        }
+    }
+}
+
 #[derive(ThisError, Debug)]
 pub enum NetworkError {
     #[error("failed to create HTTP client: {0}")]
     ClientCreation(#[source] reqwest::Error),
 
+    #[error("failed to load CA certificate from {path}")]
+    CaCertificate {
+        path: String,
+        #[source]
+        source: io::Error,
+    },
+
     #[error("DNS resolution failed for {url}")]
     DnsFailure {
         url: String,
@@ -42,6 +69,19 @@ pub enum NetworkError {
         source: reqwest::Error,
     },
 
+    #[error("rate limited by {url}")]
+    RateLimited {
+        url: String,
+        /// Wait requested by the server's `Retry-After` header
+        retry_after: Option<Duration>,
+    },
+
+    #[error("authentication failed for {url}: {status}")]
+    Unauthorized { url: String, status: u16 },
+
+    #[error("server error from {url}: {status}")]
+    ServerError { url: String, status: u16 },
+
     #[error("failed to parse JSON response from {url}")]
     JsonParse {
         url: String,
@@ -76,6 +116,9 @@ impl NetworkError {
             NetworkError::Other(error)
         } else if error.is_status() {
             if let Some(status) = error.status() {
+                if let Some(classified) = Self::from_status(url, status.as_u16(), None) {
+                    return classified;
+                }
                 return NetworkError::HttpError {
                     url: url.to_string(),
                     status: status.as_u16(),
@@ -93,12 +136,79 @@ impl NetworkError {
         }
     }
 
+    /// Dedicated error for a rate limit, an authentication failure or a
+    /// server error. Other statuses return `None`.
+    pub fn from_status(url: &str, status: u16, retry_after: Option<Duration>) -> Option<Self> {
+        let url = url.to_string();
+        match status {
+            429 => Some(NetworkError::RateLimited { url, retry_after }),
+            401 | 403 => Some(NetworkError::Unauthorized { url, status }),
+            500..=599 => Some(NetworkError::ServerError { url, status }),
+            _ => None,
+        }
+    }
+
+    /// Passes successful responses through and turns the rest into errors,
+    /// reading `Retry-After` when the server asks to slow down.
+    pub fn check_response(
+        response: reqwest::Response,
+        url: &str,
+    ) -> Result<reqwest::Response, Self> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| parse_retry_after(value, Utc::now()));
+        if let Some(error) = Self::from_status(url, status.as_u16(), retry_after) {
+            return Err(error);
+        }
+
+        response
+            .error_for_status()
+            .map_err(|source| NetworkError::HttpError {
+                url: url.to_string(),
+                status: status.as_u16(),
+                source,
+            })
+    }
+
+    /// How long the server asked us to wait before trying again.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            NetworkError::RateLimited { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    pub fn kind(&self) -> &str {
+        match self {
+            NetworkError::ClientCreation(_) => "ClientCreation",
+            NetworkError::CaCertificate { .. } => "CaCertificate",
+            NetworkError::DnsFailure { .. } => "DnsFailure",
+            NetworkError::Timeout { .. } => "Timeout",
+            NetworkError::ConnectionRefused { .. } => "ConnectionRefused",
+            NetworkError::HttpError { .. } => "HttpError",
+            NetworkError::RateLimited { .. } => "RateLimited",
+            NetworkError::Unauthorized { .. } => "Unauthorized",
+            NetworkError::ServerError { .. } => "ServerError",
+            NetworkError::JsonParse { .. } => "JsonParse",
+            NetworkError::Other(_) => "Other",
+        }
+    }
+
     pub fn is_retryable(&self) -> bool {
         matches!(
             self,
             NetworkError::Timeout { .. }
                 | NetworkError::ConnectionRefused { .. }
                 | NetworkError::DnsFailure { .. }
+                | NetworkError::RateLimited { .. }
+                | NetworkError::ServerError { .. }
         )
     }
 
@@ -118,10 +228,29 @@ impl NetworkError {
             NetworkError::HttpError { url, status, .. } => {
                 format!("Server error from {url}: HTTP {status}")
             }
+            NetworkError::RateLimited { url, retry_after } => match retry_after {
+                Some(wait) => format!(
+                    "{url} is receiving too many requests. Trying again in {}s.",
+                    wait.as_secs()
+                ),
+                None => format!("{url} is receiving too many requests. Trying again later."),
+            },
+            NetworkError::Unauthorized { url, status } => {
+                format!("{url} refused access (HTTP {status}). Check your API key or token.")
+            }
+            NetworkError::ServerError { url, status } => {
+                format!("{url} is having problems (HTTP {status}). Trying again later.")
+            }
             NetworkError::JsonParse { url, .. } => {
                 format!("Received invalid data from {url}")
             }
-            NetworkError::ClientCreation(_) => "Failed to initialize HTTP client".to_string(),
+            NetworkError::ClientCreation(_) => {
+                "Failed to initialize HTTP client. Check the [http] section of your config."
+                    .to_string()
+            }
+            NetworkError::CaCertificate { path, .. } => {
+                format!("Cannot read the CA certificate at {path}.")
+            }
             NetworkError::Other(e) => format!("Network error: {e}"),
         }
     }
@@ -147,6 +276,28 @@ pub enum ConfigError {
 
     #[error("invalid longitude: {0} (must be between -180 and 180)")]
     InvalidLongitude(f64),
+
+    #[error("invalid date '{value}' for event '{name}' (expected MM-DD)")]
+    InvalidEventDate { name: String, value: String },
+
+    #[error("invalid scene file {path} at line {line}: {message}")]
+    InvalidScene {
+        path: String,
+        line: usize,
+        message: String,
+    },
+
+    #[error("invalid density {value} for animation '{effect}' (must be a number of at least 0)")]
+    InvalidDensity { effect: String, value: f32 },
+
+    #[error("invalid {setting} in [http] (must be at least 1 second)")]
+    InvalidTimeout { setting: String },
+
+    #[error("invalid [location] provider settings: {0}")]
+    InvalidLocationProvider(String),
+
+    #[error("invalid min_distance_km {0} in [location.gpsd] (must be a number of at least 0)")]
+    InvalidDistance(f64),
 }
 
 impl ConfigError {
@@ -158,6 +309,12 @@ impl ConfigError {
             ConfigError::NoConfigDir => "NoConfigDir",
             ConfigError::InvalidLatitude(_) => "InvalidLatitude",
             ConfigError::InvalidLongitude(_) => "InvalidLongitude",
+            ConfigError::InvalidEventDate { .. } => "InvalidEventDate",
+            ConfigError::InvalidScene { .. } => "InvalidScene",
+            ConfigError::InvalidDensity { .. } => "InvalidDensity",
+            ConfigError::InvalidTimeout { .. } => "InvalidTimeout",
+            ConfigError::InvalidLocationProvider(_) => "InvalidLocationProvider",
+            ConfigError::InvalidDistance(_) => "InvalidDistance",
         }
     }
 }
@@ -226,6 +383,22 @@ pub enum GeolocationError {
 
     #[error("failed after {attempts} retry attempts")]
     RetriesExhausted { attempts: u32 },
+
+    #[error("location lookups need the network, which strict privacy mode turns off")]
+    NetworkDisabled,
+
+    #[error("no place called '{0}' found")]
+    CityNotFound(String),
+
+    #[error("location provider is misconfigured: {0}")]
+    Misconfigured(String),
+
+    #[error("cannot get a position from gpsd at {address}")]
+    GpsdUnavailable {
+        address: String,
+        #[source]
+        source: io::Error,
+    },
 }
 
 impl GeolocationError {
@@ -254,12 +427,31 @@ impl GeolocationError {
                          Using configured/default location."
                     )
                 }
+                NetworkError::RateLimited { .. } => {
+                    "Location service is receiving too many requests. Try again later.\n\
+                     Using configured/default location."
+                        .to_string()
+                }
+                NetworkError::Unauthorized { status, .. } => {
+                    format!(
+                        "Location service refused access (HTTP {status}). Check your token.\n\
+                         Using configured/default location."
+                    )
+                }
+                NetworkError::ServerError { status, .. } => {
+                    format!(
+                        "Location service is having problems (HTTP {status}).\n\
+                         Using configured/default location."
+                    )
+                }
                 NetworkError::JsonParse { .. } => "Received invalid data from location service.\n\
                      Using configured/default location."
                     .to_string(),
-                NetworkError::ClientCreation(_) => "Failed to initialize network client.\n\
+                NetworkError::ClientCreation(_) | NetworkError::CaCertificate { .. } => {
+                    "Failed to initialize network client.\n\
                      Using configured/default location."
-                    .to_string(),
+                        .to_string()
+                }
                 NetworkError::Other(_) => {
                     "Cannot auto-detect location. Check your internet connection.\n\
                      Using configured/default location."
@@ -275,6 +467,50 @@ impl GeolocationError {
                      Using configured/default location."
                 )
             }
+            GeolocationError::NetworkDisabled => {
+                "Location lookups are off in strict privacy mode.\n\
+                 Using configured/default location."
+                    .to_string()
+            }
+            GeolocationError::CityNotFound(name) => {
+                format!(
+                    "No place called '{name}' found, check the city in your config.\n\
+                     Using configured/default location."
+                )
+            }
+            GeolocationError::GpsdUnavailable { address, .. } => {
+                format!(
+                    "Cannot get a GPS position from gpsd at {address}.\n\
+                     Falling back to location auto-detection."
+                )
+            }
+            GeolocationError::Misconfigured(reason) => {
+                format!(
+                    "Location provider is misconfigured: {reason}.\n\
+                     Using configured/default location."
+                )
+            }
         }
     }
 }
+
+/// Longest wait a `Retry-After` header can ask for. Larger values are capped
+/// so a misbehaving server cannot overflow the retry deadline.
+pub const MAX_RETRY_AFTER: Duration = Duration::from_secs(3600);
+
+/// Reads a `Retry-After` header, given either as seconds or as an HTTP date.
+/// Dates in the past mean no wait, waits past `MAX_RETRY_AFTER` are capped.
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs).min(MAX_RETRY_AFTER));
+    }
+
+    let at = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (at.with_timezone(&Utc) - now)
+            .to_std()
+            .unwrap_or(Duration::ZERO)
+            .min(MAX_RETRY_AFTER),
+    )
+}
@@ -0,0 +1,120 @@
+use crate::error::WeatherError;
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Failures kept for the error history overlay
+const MAX_ENTRIES: usize = 20;
+/// How long the latest error stays in the toast area
+const TOAST_DURATION: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone)]
+pub struct ErrorEntry {
+    pub at: DateTime<Local>,
+    /// Error variant, such as "Timeout" or "DnsFailure"
+    pub kind: String,
+    pub message: String,
+}
+
+/// Recent fetch failures, newest first.
+#[derive(Debug, Default)]
+pub struct ErrorHistory {
+    entries: VecDeque<ErrorEntry>,
+    last_pushed: Option<Instant>,
+}
+
+impl ErrorHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, error: &WeatherError) {
+        self.entries.push_front(ErrorEntry {
+            at: Local::now(),
+            kind: error.kind().to_string(),
+            message: error.user_friendly_message(),
+        });
+        self.entries.truncate(MAX_ENTRIES);
+        self.last_pushed = Some(Instant::now());
+    }
+
+    /// Hides the toast early, once a fetch has succeeded again.
+    pub fn dismiss_toast(&mut self) {
+        self.last_pushed = None;
+    }
+
+    /// Text of the toast for the latest error while it is still fresh.
+    pub fn toast(&self) -> Option<String> {
+        let last_pushed = self.last_pushed?;
+        if last_pushed.elapsed() >= TOAST_DURATION {
+            return None;
+        }
+        let entry = self.entries.front()?;
+        Some(format!("{} {}", entry.at.format("%H:%M"), entry.message))
+    }
+
+    /// One line per failure for the history overlay.
+    pub fn overlay_lines(&self) -> Vec<String> {
+        if self.entries.is_empty() {
+            return vec!["No errors so far".to_string()];
+        }
+
+        self.entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    "{}  {:<17}  {}",
+                    entry.at.format("%H:%M:%S"),
+                    entry.kind,
+                    entry.message
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::NetworkError;
+
+    fn timeout() -> WeatherError {
+        WeatherError::Network(NetworkError::Timeout {
+            url: "https://api.open-meteo.com".to_string(),
+            timeout_secs: 10,
+        })
+    }
+
+    #[test]
+    fn test_history_is_newest_first_and_bounded() {
+        let mut history = ErrorHistory::new();
+        assert_eq!(history.overlay_lines(), vec!["No errors so far"]);
+
+        for _ in 0..MAX_ENTRIES {
+            history.push(&timeout());
+        }
+        history.push(&WeatherError::Network(NetworkError::ConnectionRefused {
+            url: "https://api.open-meteo.com".to_string(),
+        }));
+
+        let lines = history.overlay_lines();
+        assert_eq!(lines.len(), MAX_ENTRIES);
+        assert!(lines[0].contains("ConnectionRefused"));
+        assert!(lines[1].contains("Timeout"));
+    }
+
+    #[test]
+    fn test_toast_shows_latest_error_until_dismissed() {
+        let mut history = ErrorHistory::new();
+        assert!(history.toast().is_none());
+
+        history.push(&timeout());
+        let toast = history.toast().unwrap();
+        assert!(toast.contains("timed out after 10s"));
+        assert_eq!(toast.as_bytes()[2], b':');
+
+        history.dismiss_toast();
+        assert!(history.toast().is_none());
+        assert_eq!(history.overlay_lines().len(), 1);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::config::CustomLocationConfig;
+use crate::error::GeolocationError;
+use crate::geolocation::{GeoLocation, HttpLookup, LocationProvider};
+use async_trait::async_trait;
+use serde_json::Value;
+
+/// Asks any endpoint that answers with JSON, reading the coordinates
+/// through the JSON pointers from `[location.custom]`.
+pub struct CustomProvider {
+    lookup: HttpLookup,
+    config: CustomLocationConfig,
+}
+
+impl CustomProvider {
+    pub fn new(lookup: HttpLookup, config: CustomLocationConfig) -> Self {
+        Self { lookup, config }
+    }
+}
+
+#[async_trait]
+impl LocationProvider for CustomProvider {
+    fn name(&self) -> &'static str {
+        "custom"
+    }
+
+    async fn locate(&self) -> Result<GeoLocation, GeolocationError> {
+        let body: Value = self.lookup.get_json(&self.config.url, None).await?;
+        read_location(&body, &self.config)
+    }
+}
+
+fn read_location(
+    body: &Value,
+    config: &CustomLocationConfig,
+) -> Result<GeoLocation, GeolocationError> {
+    let latitude = read_coordinate(body, &config.latitude_pointer, 90.0)?;
+    let longitude = read_coordinate(body, &config.longitude_pointer, 180.0)?;
+    let city = config
+        .city_pointer
+        .as_deref()
+        .and_then(|pointer| body.pointer(pointer))
+        .and_then(Value::as_str)
+        .map(str::to_string);
+
+    Ok(GeoLocation {
+        latitude,
+        longitude,
+        city,
+    })
+}
+
+/// Reads a number, or a string holding one, at `pointer`. Anything beyond
+/// `±limit` degrees is rejected.
+fn read_coordinate(body: &Value, pointer: &str, limit: f64) -> Result<f64, GeolocationError> {
+    let value = body
+        .pointer(pointer)
+        .ok_or_else(|| GeolocationError::ParseError(format!("Nothing found at {pointer}")))?;
+
+    let coordinate: f64 = match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => text.trim().parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| GeolocationError::ParseError(format!("Value at {pointer} is not a number")))?;
+
+    if !coordinate.is_finite() || coordinate.abs() > limit {
+        return Err(GeolocationError::ParseError(format!(
+            "Value {coordinate} at {pointer} is not between -{limit} and {limit}"
+        )));
+    }
+    Ok(coordinate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(latitude: &str, longitude: &str, city: Option<&str>) -> CustomLocationConfig {
+        CustomLocationConfig {
+            url: "https://geo.example.com".to_string(),
+            latitude_pointer: latitude.to_string(),
+            longitude_pointer: longitude.to_string(),
+            city_pointer: city.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_read_location() {
+        let body: Value = serde_json::from_str(
+            r#"{"position": {"lat": 48.8566, "lng": "2.3522"}, "place": {"name": "Paris"}}"#,
+        )
+        .unwrap();
+
+        let location = read_location(
+            &body,
+            &config("/position/lat", "/position/lng", Some("/place/name")),
+        )
+        .unwrap();
+        assert_eq!(location.latitude, 48.8566);
+        assert_eq!(location.longitude, 2.3522);
+        assert_eq!(location.city.as_deref(), Some("Paris"));
+
+        let error = read_location(&body, &config("/lat", "/position/lng", None)).unwrap_err();
+        assert!(error.to_string().contains("/lat"));
+
+        let error = read_location(&body, &config("/place", "/position/lng", None)).unwrap_err();
+        assert!(error.to_string().contains("not a number"));
+    }
+
+    #[test]
+    fn test_read_location_rejects_impossible_coordinates() {
+        let body: Value = serde_json::from_str(
+            r#"{"nan": "NaN", "inf": "inf", "far": "999", "south": -90.5, "lng": 181, "ok": "-33.87"}"#,
+        )
+        .unwrap();
+
+        for (latitude, longitude) in [
+            ("/nan", "/ok"),
+            ("/inf", "/ok"),
+            ("/far", "/ok"),
+            ("/south", "/ok"),
+            ("/ok", "/lng"),
+        ] {
+            let error = read_location(&body, &config(latitude, longitude, None)).unwrap_err();
+            assert!(
+                matches!(error, GeolocationError::ParseError(_)),
+                "{latitude} {longitude}"
+            );
+        }
+
+        let location = read_location(&body, &config("/ok", "/ok", None)).unwrap();
+        assert_eq!(location.latitude, -33.87);
+    }
+}
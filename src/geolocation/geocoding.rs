@@ -0,0 +1,150 @@
+use crate::cache;
+use crate::config::Config;
+use crate::error::GeolocationError;
+use crate::geolocation::{GeoLocation, HttpLookup};
+use serde::Deserialize;
+
+const GEOCODING_URL: &str = "https://geocoding-api.open-meteo.com/v1/search";
+const MAX_RESULTS: &str = "10";
+
+#[derive(Deserialize, Debug)]
+struct SearchResponse {
+    /// Missing altogether when nothing matches
+    #[serde(default)]
+    results: Vec<Place>,
+}
+
+/// A match from the Open-Meteo geocoding API.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Place {
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// State, province or other first-level region
+    #[serde(default)]
+    pub admin1: Option<String>,
+    #[serde(default)]
+    pub country: Option<String>,
+}
+
+impl Place {
+    /// Name, region and country, enough to tell places of the same name apart.
+    pub fn label(&self) -> String {
+        let mut parts = vec![self.name.as_str()];
+        if let Some(region) = self.admin1.as_deref().filter(|r| *r != self.name) {
+            parts.push(region);
+        }
+        if let Some(country) = &self.country {
+            parts.push(country);
+        }
+        parts.join(", ")
+    }
+}
+
+/// Turns a city name into coordinates. When several places share the name,
+/// `pick` chooses one by index. Answers are cached, so each name is only
+/// looked up (and asked about) once.
+pub async fn resolve_city(
+    name: &str,
+    config: &Config,
+    pick: impl FnOnce(&[Place]) -> usize,
+) -> Result<GeoLocation, GeolocationError> {
+    if let Some(cached) = cache::load_cached_city(name).await {
+        return Ok(cached);
+    }
+    if !config.privacy.allows_network() {
+        return Err(GeolocationError::NetworkDisabled);
+    }
+
+    let lookup = HttpLookup::new(&config.http)?;
+    let url = reqwest::Url::parse_with_params(
+        GEOCODING_URL,
+        &[
+            ("name", name),
+            ("count", MAX_RESULTS),
+            ("language", "en"),
+            ("format", "json"),
+        ],
+    )
+    .map_err(|e| GeolocationError::ParseError(e.to_string()))?;
+    let response: SearchResponse = lookup.get_json(url.as_str(), None).await?;
+
+    let mut candidates = candidates(name, response.results);
+    let place = match candidates.len() {
+        0 => return Err(GeolocationError::CityNotFound(name.to_string())),
+        1 => candidates.remove(0),
+        count => {
+            let index = pick(&candidates).min(count - 1);
+            candidates.remove(index)
+        }
+    };
+
+    let location = GeoLocation {
+        latitude: place.latitude,
+        longitude: place.longitude,
+        city: Some(place.name),
+    };
+    cache::save_city_cache(name, &location);
+    Ok(location)
+}
+
+/// The places worth offering for `name`. A search also returns places whose
+/// name merely starts the same way, so exact matches win when there are any.
+fn candidates(name: &str, places: Vec<Place>) -> Vec<Place> {
+    let name = name.trim();
+    if places.iter().any(|p| p.name.eq_ignore_ascii_case(name)) {
+        places
+            .into_iter()
+            .filter(|p| p.name.eq_ignore_ascii_case(name))
+            .collect()
+    } else {
+        places
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn place(name: &str, admin1: Option<&str>, country: &str) -> Place {
+        Place {
+            name: name.to_string(),
+            latitude: 0.0,
+            longitude: 0.0,
+            admin1: admin1.map(str::to_string),
+            country: Some(country.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_search_response() {
+        let response: SearchResponse = serde_json::from_str(
+            r#"{"results": [{"id": 1850147, "name": "Tokyo", "latitude": 35.6895,
+                "longitude": 139.69171, "country_code": "JP", "admin1": "Tokyo",
+                "country": "Japan"}], "generationtime_ms": 0.5}"#,
+        )
+        .unwrap();
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results[0].label(), "Tokyo, Japan");
+
+        let response: SearchResponse =
+            serde_json::from_str(r#"{"generationtime_ms": 0.3}"#).unwrap();
+        assert!(response.results.is_empty());
+    }
+
+    #[test]
+    fn test_candidates_prefer_exact_names() {
+        let places = vec![
+            place("Paris", Some("Île-de-France"), "France"),
+            place("Parisot", Some("Occitanie"), "France"),
+            place("Paris", Some("Texas"), "United States"),
+        ];
+
+        let found = candidates("paris", places.clone());
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[1].label(), "Paris, Texas, United States");
+
+        let found = candidates("Pari", places);
+        assert_eq!(found.len(), 3);
+    }
+}
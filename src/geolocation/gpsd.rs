@@ -0,0 +1,314 @@
+use crate::config::GpsdConfig;
+use crate::error::GeolocationError;
+use crate::geolocation::GeoLocation;
+use serde::Deserialize;
+use std::io;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::net::TcpStream;
+use tokio::net::tcp::OwnedReadHalf;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+/// How long to wait at startup for gpsd to report a usable fix
+const FIRST_FIX_TIMEOUT: Duration = Duration::from_secs(5);
+/// Pause before connecting again after gpsd went away
+const RECONNECT_DELAY: Duration = Duration::from_secs(10);
+
+const WATCH_COMMAND: &[u8] = b"?WATCH={\"enable\":true,\"json\":true}\n";
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// One line of gpsd's JSON stream. Only time-position-velocity reports
+/// (`"class": "TPV"`) are of interest.
+#[derive(Deserialize, Debug)]
+struct Report {
+    class: String,
+    /// 0 or 1 while the receiver has no fix, 2 for 2D and 3 for 3D
+    #[serde(default)]
+    mode: u8,
+    lat: Option<f64>,
+    lon: Option<f64>,
+}
+
+impl Report {
+    fn fix(&self) -> Option<GeoLocation> {
+        if self.class != "TPV" || self.mode < 2 {
+            return None;
+        }
+        Some(GeoLocation {
+            latitude: self.lat?,
+            longitude: self.lon?,
+            city: None,
+        })
+    }
+}
+
+/// A connection to gpsd with watching turned on.
+struct GpsdClient {
+    address: String,
+    lines: Lines<BufReader<OwnedReadHalf>>,
+}
+
+impl GpsdClient {
+    async fn connect(config: &GpsdConfig) -> Result<Self, GeolocationError> {
+        let address = format!("{}:{}", config.host, config.port);
+        let unavailable = |source| GeolocationError::GpsdUnavailable {
+            address: address.clone(),
+            source,
+        };
+
+        let stream = TcpStream::connect(&address).await.map_err(unavailable)?;
+        let (reader, mut writer) = stream.into_split();
+        writer.write_all(WATCH_COMMAND).await.map_err(unavailable)?;
+        tracing::info!(address, "connected to gpsd");
+
+        Ok(Self {
+            address,
+            lines: BufReader::new(reader).lines(),
+        })
+    }
+
+    /// Waits for the next report that carries a position.
+    async fn next_fix(&mut self) -> Result<GeoLocation, GeolocationError> {
+        loop {
+            let line = self.lines.next_line().await.map_err(|source| {
+                GeolocationError::GpsdUnavailable {
+                    address: self.address.clone(),
+                    source,
+                }
+            })?;
+            let Some(line) = line else {
+                return Err(GeolocationError::GpsdUnavailable {
+                    address: self.address.clone(),
+                    source: io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "gpsd closed the connection",
+                    ),
+                });
+            };
+
+            match serde_json::from_str::<Report>(&line) {
+                Ok(report) => {
+                    if let Some(fix) = report.fix() {
+                        return Ok(fix);
+                    }
+                }
+                Err(e) => tracing::debug!(error = %e, "skipping unreadable gpsd report"),
+            }
+        }
+    }
+}
+
+/// Asks gpsd for the current position, giving up when the receiver has no
+/// fix within a few seconds.
+pub async fn first_fix(config: &GpsdConfig) -> Result<GeoLocation, GeolocationError> {
+    let lookup = async {
+        let mut client = GpsdClient::connect(config).await?;
+        client.next_fix().await
+    };
+
+    tokio::time::timeout(FIRST_FIX_TIMEOUT, lookup)
+        .await
+        .unwrap_or_else(|_| {
+            Err(GeolocationError::GpsdUnavailable {
+                address: format!("{}:{}", config.host, config.port),
+                source: io::Error::new(io::ErrorKind::TimedOut, "no fix from the GPS receiver"),
+            })
+        })
+}
+
+/// Great-circle distance between two positions, in kilometres.
+fn distance_km(from: &GeoLocation, to: &GeoLocation) -> f64 {
+    let (lat1, lat2) = (from.latitude.to_radians(), to.latitude.to_radians());
+    let dlat = lat2 - lat1;
+    let dlon = (to.longitude - from.longitude).to_radians();
+
+    let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+/// Follows gpsd in the background and reports the position each time it
+/// has moved `min_distance_km` away from the last one reported.
+pub struct GpsdWatcher {
+    task: JoinHandle<()>,
+    receiver: mpsc::Receiver<GeoLocation>,
+}
+
+impl GpsdWatcher {
+    /// Starts watching. `start` is the position the app is already using.
+    pub fn spawn(config: GpsdConfig, start: GeoLocation) -> Self {
+        let (tx, receiver) = mpsc::channel(1);
+
+        let task = tokio::spawn(async move {
+            let mut last = start;
+            loop {
+                let mut client = match GpsdClient::connect(&config).await {
+                    Ok(client) => client,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "gpsd unavailable, retrying later");
+                        tokio::time::sleep(RECONNECT_DELAY).await;
+                        continue;
+                    }
+                };
+
+                loop {
+                    match client.next_fix().await {
+                        Ok(fix) => {
+                            let moved = distance_km(&last, &fix);
+                            if moved < config.min_distance_km {
+                                continue;
+                            }
+                            tracing::info!(
+                                latitude = fix.latitude,
+                                longitude = fix.longitude,
+                                moved_km = moved,
+                                "position changed"
+                            );
+                            last = fix.clone();
+                            if tx.send(fix).await.is_err() {
+                                return;
+                            }
+                        }
+                        Err(e) => {
+                            tracing::warn!(error = %e, "lost gpsd, reconnecting");
+                            break;
+                        }
+                    }
+                }
+                tokio::time::sleep(RECONNECT_DELAY).await;
+            }
+        });
+
+        Self { task, receiver }
+    }
+
+    pub async fn recv(&mut self) -> Option<GeoLocation> {
+        self.receiver.recv().await
+    }
+}
+
+impl Drop for GpsdWatcher {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    fn tpv(mode: u8, lat: f64, lon: f64) -> String {
+        format!(
+            r#"{{"class":"TPV","device":"/dev/ttyUSB0","mode":{mode},"lat":{lat},"lon":{lon}}}"#
+        )
+    }
+
+    /// Pretends to be gpsd: greets the client, checks it asks to watch,
+    /// then plays back `reports` one line each.
+    async fn fake_gpsd(reports: Vec<String>) -> GpsdConfig {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = stream.into_split();
+            writer
+                .write_all(b"{\"class\":\"VERSION\",\"release\":\"3.25\",\"proto_major\":3}\n")
+                .await
+                .unwrap();
+
+            let mut lines = BufReader::new(reader).lines();
+            let command = lines.next_line().await.unwrap().unwrap();
+            assert!(command.starts_with("?WATCH="));
+
+            for report in reports {
+                writer.write_all(report.as_bytes()).await.unwrap();
+                writer.write_all(b"\n").await.unwrap();
+            }
+            // Hold the connection open until the client hangs up
+            let _ = lines.next_line().await;
+        });
+
+        GpsdConfig {
+            enabled: true,
+            port,
+            ..GpsdConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_first_fix_skips_reports_without_position() {
+        let config = fake_gpsd(vec![
+            r#"{"class":"DEVICES","devices":[]}"#.to_string(),
+            "not json".to_string(),
+            tpv(1, 0.0, 0.0),
+            r#"{"class":"SKY","satellites":[]}"#.to_string(),
+            tpv(3, 59.3293, 18.0686),
+        ])
+        .await;
+
+        let fix = first_fix(&config).await.unwrap();
+        assert_eq!(fix.latitude, 59.3293);
+        assert_eq!(fix.longitude, 18.0686);
+    }
+
+    #[tokio::test]
+    async fn test_first_fix_without_gpsd() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let config = GpsdConfig {
+            enabled: true,
+            port,
+            ..GpsdConfig::default()
+        };
+        let error = first_fix(&config).await.unwrap_err();
+        assert!(matches!(error, GeolocationError::GpsdUnavailable { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_watcher_reports_only_real_moves() {
+        let mut config = fake_gpsd(vec![
+            tpv(3, 52.5201, 13.4051),
+            tpv(3, 52.5300, 13.4100),
+            tpv(3, 52.3906, 13.0645),
+            tpv(3, 52.3910, 13.0650),
+            tpv(3, 53.5511, 9.9937),
+        ])
+        .await;
+        config.min_distance_km = 5.0;
+
+        let start = GeoLocation {
+            latitude: 52.52,
+            longitude: 13.405,
+            city: None,
+        };
+        let mut watcher = GpsdWatcher::spawn(config, start);
+
+        let potsdam = watcher.recv().await.unwrap();
+        assert_eq!(potsdam.latitude, 52.3906);
+        let hamburg = watcher.recv().await.unwrap();
+        assert_eq!(hamburg.latitude, 53.5511);
+    }
+
+    #[test]
+    fn test_distance_km() {
+        let berlin = GeoLocation {
+            latitude: 52.52,
+            longitude: 13.405,
+            city: None,
+        };
+        let paris = GeoLocation {
+            latitude: 48.8566,
+            longitude: 2.3522,
+            city: None,
+        };
+
+        assert_eq!(distance_km(&berlin, &berlin), 0.0);
+        let distance = distance_km(&berlin, &paris);
+        assert!((distance - 878.0).abs() < 5.0, "{distance}");
+    }
+}
@@ -0,0 +1,82 @@
+use crate::error::GeolocationError;
+use crate::geolocation::{GeoLocation, HttpLookup, LocationProvider};
+use async_trait::async_trait;
+use serde::Deserialize;
+
+// The free tier of ip-api.com is only served over plain HTTP
+const IP_API_URL: &str = "http://ip-api.com/json/?fields=status,message,lat,lon,city";
+
+#[derive(Deserialize, Debug)]
+struct IpApiResponse {
+    status: String,
+    message: Option<String>,
+    lat: Option<f64>,
+    lon: Option<f64>,
+    city: Option<String>,
+}
+
+/// Looks the public IP up on ip-api.com.
+pub struct IpApiProvider {
+    lookup: HttpLookup,
+}
+
+impl IpApiProvider {
+    pub fn new(lookup: HttpLookup) -> Self {
+        Self { lookup }
+    }
+}
+
+#[async_trait]
+impl LocationProvider for IpApiProvider {
+    fn name(&self) -> &'static str {
+        "ip-api"
+    }
+
+    async fn locate(&self) -> Result<GeoLocation, GeolocationError> {
+        let response: IpApiResponse = self.lookup.get_json(IP_API_URL, None).await?;
+        parse_response(response)
+    }
+}
+
+/// ip-api.com answers lookups it cannot serve with `200 OK` and
+/// `"status": "fail"`, so the status has to be checked here.
+fn parse_response(response: IpApiResponse) -> Result<GeoLocation, GeolocationError> {
+    if response.status != "success" {
+        let reason = response.message.as_deref().unwrap_or("unknown error");
+        return Err(GeolocationError::ParseError(format!(
+            "ip-api.com lookup failed: {reason}"
+        )));
+    }
+
+    match (response.lat, response.lon) {
+        (Some(latitude), Some(longitude)) => Ok(GeoLocation {
+            latitude,
+            longitude,
+            city: response.city,
+        }),
+        _ => Err(GeolocationError::ParseError(
+            "Missing coordinates from ip-api.com".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_response() {
+        let response: IpApiResponse = serde_json::from_str(
+            r#"{"status": "success", "lat": 35.6895, "lon": 139.6917, "city": "Tokyo"}"#,
+        )
+        .unwrap();
+        let location = parse_response(response).unwrap();
+        assert_eq!(location.latitude, 35.6895);
+        assert_eq!(location.city.as_deref(), Some("Tokyo"));
+
+        let response: IpApiResponse =
+            serde_json::from_str(r#"{"status": "fail", "message": "private range"}"#).unwrap();
+        let error = parse_response(response).unwrap_err();
+        assert!(error.to_string().contains("private range"));
+    }
+}
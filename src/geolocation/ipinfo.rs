@@ -0,0 +1,89 @@
+use crate::error::GeolocationError;
+use crate::geolocation::{GeoLocation, HttpLookup, LocationProvider};
+use async_trait::async_trait;
+use serde::Deserialize;
+
+const IPINFO_URL: &str = "https://ipinfo.io/json";
+
+#[derive(Deserialize, Debug)]
+struct IpInfoResponse {
+    loc: String,
+    city: Option<String>,
+}
+
+/// Looks the public IP up on ipinfo.io. Without a token the anonymous
+/// rate limit applies.
+pub struct IpinfoProvider {
+    lookup: HttpLookup,
+    token: Option<String>,
+}
+
+impl IpinfoProvider {
+    pub fn new(lookup: HttpLookup, token: Option<String>) -> Self {
+        Self { lookup, token }
+    }
+}
+
+#[async_trait]
+impl LocationProvider for IpinfoProvider {
+    fn name(&self) -> &'static str {
+        "ipinfo"
+    }
+
+    async fn locate(&self) -> Result<GeoLocation, GeolocationError> {
+        let response: IpInfoResponse = self
+            .lookup
+            .get_json(IPINFO_URL, self.token.as_deref())
+            .await?;
+        parse_response(response)
+    }
+}
+
+fn parse_response(response: IpInfoResponse) -> Result<GeoLocation, GeolocationError> {
+    let coords: Vec<&str> = response.loc.split(',').collect();
+    if coords.len() != 2 {
+        return Err(GeolocationError::ParseError(
+            "Invalid location format from ipinfo.io".to_string(),
+        ));
+    }
+
+    let latitude = coords[0]
+        .parse::<f64>()
+        .map_err(|_| GeolocationError::ParseError("Invalid latitude format".to_string()))?;
+
+    let longitude = coords[1]
+        .parse::<f64>()
+        .map_err(|_| GeolocationError::ParseError("Invalid longitude format".to_string()))?;
+
+    Ok(GeoLocation {
+        latitude,
+        longitude,
+        city: response.city,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_response() {
+        let response: IpInfoResponse = serde_json::from_str(
+            r#"{"ip": "192.0.2.1", "loc": "52.5200,13.4050", "city": "Berlin"}"#,
+        )
+        .unwrap();
+        let location = parse_response(response).unwrap();
+        assert_eq!(location.latitude, 52.52);
+        assert_eq!(location.longitude, 13.405);
+        assert_eq!(location.city.as_deref(), Some("Berlin"));
+
+        let response = IpInfoResponse {
+            loc: "52.52".to_string(),
+            city: None,
+        };
+        assert!(matches!(
+            parse_response(response),
+            Err(GeolocationError::ParseError(_))
+        ));
+    }
+}
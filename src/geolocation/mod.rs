@@ -0,0 +1,220 @@
+pub mod custom;
+pub mod fixed;
+pub mod geocoding;
+pub mod gpsd;
+pub mod ip_api;
+pub mod ipinfo;
+pub mod provider;
+
+pub use provider::LocationProvider;
+
+use crate::cache;
+use crate::config::{Config, HttpConfig, Location, LocationProviderKind};
+use crate::error::{GeolocationError, NetworkError};
+use crate::http;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+const MAX_RETRIES: u32 = 3;
+const INITIAL_RETRY_DELAY_MS: u64 = 500;
+/// Longest `Retry-After` worth waiting for while the app is starting up
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeoLocation {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub city: Option<String>,
+}
+
+pub async fn detect_location(config: &Config) -> Result<GeoLocation, GeolocationError> {
+    let uses_network = config.location.provider.uses_network();
+    if uses_network {
+        // A location found earlier is still fine to use offline
+        if let Some(cached) = cache::load_cached_location().await {
+            return Ok(cached);
+        }
+        if !config.privacy.allows_network() {
+            return Err(GeolocationError::NetworkDisabled);
+        }
+    }
+
+    let provider = build_provider(&config.location, &config.http)?;
+    let location = detect_location_with_retry(provider.as_ref()).await?;
+    tracing::info!(provider = provider.name(), "location detected");
+
+    if uses_network {
+        cache::save_location_cache(&location);
+    }
+    Ok(location)
+}
+
+/// Creates the provider picked by `[location] provider`. Only providers
+/// that go online get an HTTP client.
+pub fn build_provider(
+    location: &Location,
+    http: &HttpConfig,
+) -> Result<Box<dyn LocationProvider>, GeolocationError> {
+    if location.provider == LocationProviderKind::Static {
+        return Ok(Box::new(fixed::StaticProvider::new(
+            location.latitude,
+            location.longitude,
+        )));
+    }
+
+    let lookup = HttpLookup::new(http)?;
+    let provider: Box<dyn LocationProvider> = match location.provider {
+        LocationProviderKind::Ipinfo => Box::new(ipinfo::IpinfoProvider::new(
+            lookup,
+            location.ipinfo_token.clone(),
+        )),
+        LocationProviderKind::IpApi => Box::new(ip_api::IpApiProvider::new(lookup)),
+        LocationProviderKind::Custom => {
+            let config = location.custom.clone().ok_or_else(|| {
+                GeolocationError::Misconfigured(
+                    "provider = \"custom\" needs a [location.custom] table".to_string(),
+                )
+            })?;
+            Box::new(custom::CustomProvider::new(lookup, config))
+        }
+        LocationProviderKind::Static => unreachable!("handled above"),
+    };
+    Ok(provider)
+}
+
+async fn detect_location_with_retry(
+    provider: &dyn LocationProvider,
+) -> Result<GeoLocation, GeolocationError> {
+    let mut last_error = None;
+
+    for attempt in 1..=MAX_RETRIES {
+        match provider.locate().await {
+            Ok(location) => return Ok(location),
+            Err(e) => {
+                let should_retry = matches!(
+                    e,
+                    GeolocationError::Unreachable(ref net_err) if net_err.is_retryable()
+                );
+                let retry_after = match &e {
+                    GeolocationError::Unreachable(net_err) => net_err.retry_after(),
+                    _ => None,
+                };
+
+                if !should_retry
+                    || attempt == MAX_RETRIES
+                    || retry_after.is_some_and(|wait| wait > MAX_RETRY_AFTER)
+                {
+                    return Err(e);
+                }
+
+                let delay = Duration::from_millis(INITIAL_RETRY_DELAY_MS * 2_u64.pow(attempt - 1));
+                tokio::time::sleep(delay.max(retry_after.unwrap_or_default())).await;
+                last_error = Some(e);
+            }
+        }
+    }
+
+    Err(
+        last_error.unwrap_or_else(|| GeolocationError::RetriesExhausted {
+            attempts: MAX_RETRIES,
+        }),
+    )
+}
+
+/// The HTTP client shared by the providers that ask a web service.
+pub struct HttpLookup {
+    client: reqwest::Client,
+    timeout_secs: u64,
+}
+
+impl HttpLookup {
+    pub fn new(http: &HttpConfig) -> Result<Self, GeolocationError> {
+        let client = http::build_client(http).map_err(GeolocationError::Unreachable)?;
+        Ok(Self {
+            client,
+            timeout_secs: http.timeout_secs,
+        })
+    }
+
+    /// Sends a GET to `url`, with `token` as a bearer token if given, and
+    /// decodes the JSON answer.
+    pub async fn get_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        token: Option<&str>,
+    ) -> Result<T, GeolocationError> {
+        let mut request = self.client.get(url);
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+
+        let started = Instant::now();
+        let response = request.send().await.map_err(|e| {
+            let error = NetworkError::from_reqwest(e, url, self.timeout_secs);
+            tracing::warn!(
+                url,
+                latency_ms = started.elapsed().as_millis() as u64,
+                kind = error.kind(),
+                "location request failed"
+            );
+            GeolocationError::Unreachable(error)
+        })?;
+        tracing::info!(
+            url,
+            status = response.status().as_u16(),
+            latency_ms = started.elapsed().as_millis() as u64,
+            "location request"
+        );
+        let response =
+            NetworkError::check_response(response, url).map_err(GeolocationError::Unreachable)?;
+
+        response.json().await.map_err(|e| {
+            GeolocationError::Unreachable(NetworkError::from_reqwest(e, url, self.timeout_secs))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Privacy;
+
+    #[tokio::test]
+    async fn test_static_provider_works_offline() {
+        let mut config = Config {
+            privacy: Privacy::Strict,
+            ..Config::default()
+        };
+        config.location.provider = LocationProviderKind::Static;
+        config.location.latitude = 35.6895;
+        config.location.longitude = 139.6917;
+
+        let location = detect_location(&config).await.unwrap();
+        assert_eq!(location.latitude, 35.6895);
+        assert_eq!(location.longitude, 139.6917);
+        assert_eq!(http::clients_created(), 0);
+
+        config.location.provider = LocationProviderKind::IpApi;
+        assert!(matches!(
+            detect_location(&config).await,
+            Err(GeolocationError::NetworkDisabled)
+        ));
+    }
+
+    #[test]
+    fn test_build_provider() {
+        let http = HttpConfig::default();
+        let mut location = Location {
+            provider: LocationProviderKind::IpApi,
+            ..Location::default()
+        };
+        assert_eq!(build_provider(&location, &http).unwrap().name(), "ip-api");
+
+        location.provider = LocationProviderKind::Custom;
+        assert!(matches!(
+            build_provider(&location, &http),
+            Err(GeolocationError::Misconfigured(_))
+        ));
+    }
+}
@@ -0,0 +1,11 @@
+use crate::error::GeolocationError;
+use crate::geolocation::GeoLocation;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait LocationProvider: Send + Sync {
+    /// Short name used in the log
+    fn name(&self) -> &'static str;
+
+    async fn locate(&self) -> Result<GeoLocation, GeolocationError>;
+}
@@ -0,0 +1,90 @@
+use std::ffi::OsString;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// Log level for the number of `-v` flags. Nothing is logged without one.
+fn level_for(verbosity: u8) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::OFF,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+fn log_path_from(state_home: Option<OsString>, home: Option<PathBuf>) -> Option<PathBuf> {
+    let state_dir = match state_home {
+        Some(state_home) if !state_home.is_empty() => PathBuf::from(state_home),
+        _ => home?.join(".local").join("state"),
+    };
+    Some(state_dir.join("weathr").join("weathr.log"))
+}
+
+/// `$XDG_STATE_HOME/weathr/weathr.log`, or `~/.local/state/weathr/weathr.log`
+pub fn log_path() -> Option<PathBuf> {
+    log_path_from(std::env::var_os("XDG_STATE_HOME"), dirs::home_dir())
+}
+
+/// Starts writing this crate's log events to the log file. The terminal is
+/// never written to, as the scene owns it. Returns the path of the log, or
+/// `None` when `verbosity` is 0.
+pub fn init(verbosity: u8) -> io::Result<Option<PathBuf>> {
+    let level = level_for(verbosity);
+    if level == LevelFilter::OFF {
+        return Ok(None);
+    }
+
+    let path = log_path()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "cannot find the home directory"))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let file = OpenOptions::new().create(true).append(true).open(&path)?;
+
+    // Dependencies stay quiet so the log only tells weathr's story
+    let targets = Targets::new().with_target(env!("CARGO_CRATE_NAME"), level);
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(Mutex::new(file))
+                .with_ansi(false),
+        )
+        .with(targets)
+        .init();
+
+    Ok(Some(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_path_prefers_xdg_state_home() {
+        let home = Some(PathBuf::from("/home/ana"));
+
+        assert_eq!(
+            log_path_from(Some("/var/state".into()), home.clone()),
+            Some(PathBuf::from("/var/state/weathr/weathr.log"))
+        );
+        assert_eq!(
+            log_path_from(Some("".into()), home.clone()),
+            Some(PathBuf::from("/home/ana/.local/state/weathr/weathr.log"))
+        );
+        assert_eq!(log_path_from(None, None), None);
+    }
+
+    #[test]
+    fn test_verbosity_levels() {
+        assert_eq!(level_for(0), LevelFilter::OFF);
+        assert_eq!(level_for(1), LevelFilter::INFO);
+        assert_eq!(level_for(2), LevelFilter::DEBUG);
+        assert_eq!(level_for(5), LevelFilter::TRACE);
+    }
+}
@@ -5,9 +5,13 @@ mod app_state;
 mod cache;
 mod config;
 mod error;
+mod error_history;
 mod geolocation;
+mod http;
+mod logging;
 mod render;
 mod scene;
+mod signals;
 mod weather;
 
 use clap::Parser;
@@ -18,6 +22,11 @@ use crossterm::{
     terminal::{LeaveAlternateScreen, disable_raw_mode},
 };
 use render::TerminalRenderer;
+use scene::WorldScene;
+use scene::events::EventCalendar;
+use scene::season::Season;
+use scene::themes::cottage::CottageTheme;
+use std::io::{IsTerminal, Write};
 use std::{io, panic};
 
 const LONG_VERSION: &str = concat!(
@@ -32,7 +41,7 @@ fn info(silent: bool, msg: &str) {
     }
 }
 
-#[derive(Parser)]
+#[derive(Parser, Clone)]
 #[command(version, long_version = LONG_VERSION, about = "Terminal-based ASCII weather application", long_about = None)]
 struct Cli {
     #[arg(
@@ -50,12 +59,36 @@ struct Cli {
     )]
     night: bool,
 
-    #[arg(short, long, help = "Enable falling autumn leaves")]
+    #[arg(
+        short,
+        long,
+        help = "Enable falling autumn leaves (same as [animations.leaves] enabled = true)"
+    )]
     leaves: bool,
 
-    #[arg(long, help = "Auto-detect location via IP (uses ipinfo.io)")]
+    #[arg(
+        long,
+        value_name = "SCENE",
+        help = "Scene theme (house, city, cabin, beach, forest, office)"
+    )]
+    scene: Option<scene::themes::SceneKind>,
+
+    #[arg(
+        long,
+        value_name = "NAME",
+        help = "Show the weather for a city, looked up by name (e.g. Tokyo)"
+    )]
+    city: Option<String>,
+
+    #[arg(
+        long,
+        help = "Auto-detect location via IP (see provider in [location])"
+    )]
     auto_location: bool,
 
+    #[arg(long, help = "Follow the GPS position reported by gpsd on localhost")]
+    gpsd: bool,
+
     #[arg(long, help = "Hide location coordinates in UI")]
     hide_location: bool,
 
@@ -78,8 +111,106 @@ struct Cli {
 
     #[arg(long, help = "Run silently (suppress non-error output)")]
     silent: bool,
+
+    #[arg(
+        long,
+        help = "Never connect to the network (same as privacy = \"strict\"), use cached weather only"
+    )]
+    no_network: bool,
+
+    #[arg(
+        short,
+        long,
+        action = clap::ArgAction::Count,
+        help = "Write a log to $XDG_STATE_HOME/weathr/weathr.log (-v info, -vv debug)"
+    )]
+    verbose: u8,
+
+    #[arg(
+        long,
+        value_name = "FPS",
+        default_value_t = app::DEFAULT_FPS,
+        value_parser = clap::value_parser!(u32).range(1..=120),
+        help = "Frames drawn per second (1-120). Animation speed stays the same"
+    )]
+    fps: u32,
+}
+
+/// Applies the command line flags on top of a loaded config.
+fn apply_cli_overrides(cli: &Cli, config: &mut Config) {
+    if let Some(city) = &cli.city {
+        config.location.city = Some(city.clone());
+    }
+    if cli.auto_location {
+        config.location.auto = true;
+    }
+    if cli.gpsd {
+        config.location.gpsd.enabled = true;
+    }
+    if cli.hide_location {
+        config.location.hide = true;
+    }
+    if cli.hide_hud {
+        config.hide_hud = true;
+    }
+    if cli.imperial {
+        config.units = weather::WeatherUnits::imperial();
+    }
+    if cli.metric {
+        config.units = weather::WeatherUnits::metric();
+    }
+    if cli.silent {
+        config.silent = true;
+    }
+    if cli.no_network {
+        config.privacy = config::Privacy::Strict;
+    }
+    if let Some(scene) = &cli.scene {
+        config.scene = scene.clone();
+    }
+    if cli.leaves {
+        config.animations.leaves.enabled = Some(true);
+    }
 }
 
+/// Asks which place was meant when several share a city name. Without a
+/// terminal to ask on, the most populous one is taken.
+fn pick_place(places: &[geolocation::geocoding::Place]) -> usize {
+    if !io::stdin().is_terminal() {
+        return 0;
+    }
+
+    println!("Several places match, pick one:");
+    for (i, place) in places.iter().enumerate() {
+        println!(
+            "  {}) {} ({:.2}, {:.2})",
+            i + 1,
+            place.label(),
+            place.latitude,
+            place.longitude
+        );
+    }
+
+    loop {
+        print!("Number [1]: ");
+        let _ = io::stdout().flush();
+
+        let mut input = String::new();
+        if io::stdin().read_line(&mut input).unwrap_or(0) == 0 {
+            return 0;
+        }
+        let input = input.trim();
+        if input.is_empty() {
+            return 0;
+        }
+        match input.parse::<usize>() {
+            Ok(n) if (1..=places.len()).contains(&n) => return n - 1,
+            _ => println!("Enter a number from 1 to {}", places.len()),
+        }
+    }
+}
+
+/// Fills in the settings whose default depends on the season.
 #[tokio::main]
 async fn main() -> io::Result<()> {
     let default_hook = panic::take_hook();
@@ -131,6 +262,10 @@ async fn main() -> io::Result<()> {
         }
     };
 
+    if let Err(e) = logging::init(cli.verbose) {
+        eprintln!("Cannot open the log file: {}", e);
+    }
+
     let mut config = match Config::load() {
         Ok(config) => config,
         Err(e) => {
@@ -151,30 +286,60 @@ async fn main() -> io::Result<()> {
         }
     };
 
-    // CLI Overrides
-    if cli.auto_location {
-        config.location.auto = true;
-    }
-    if cli.hide_location {
-        config.location.hide = true;
-    }
-    if cli.hide_hud {
-        config.hide_hud = true;
-    }
-    if cli.imperial {
-        config.units = weather::WeatherUnits::imperial();
-    }
-    if cli.metric {
-        config.units = weather::WeatherUnits::metric();
+    apply_cli_overrides(&cli, &mut config);
+
+    // A city name replaces the coordinates, the result is shown in the HUD
+    if let Some(city) = config.location.city.take() {
+        info(config.silent, &format!("Looking up {}...", city));
+        match geolocation::geocoding::resolve_city(&city, &config, pick_place).await {
+            Ok(place) => {
+                info(
+                    config.silent,
+                    &format!(
+                        "Location: {} ({:.4}, {:.4})",
+                        place.city.as_deref().unwrap_or(&city),
+                        place.latitude,
+                        place.longitude
+                    ),
+                );
+                config.location.latitude = place.latitude;
+                config.location.longitude = place.longitude;
+                config.location.city = place.city;
+                config.location.auto = false;
+                config.location.gpsd.enabled = false;
+            }
+            Err(e) => {
+                eprintln!("{}", e.user_friendly_message());
+            }
+        }
     }
-    if cli.silent {
-        config.silent = true;
+
+    // A GPS receiver beats IP geolocation, which is the fallback without one
+    // In strict privacy mode only a gpsd on this machine may be asked
+    let mut auto_location = config.location.auto;
+    if config.location.gpsd.enabled && config.privacy.allows_gpsd(&config.location.gpsd) {
+        info(config.silent, "Waiting for a GPS fix from gpsd...");
+        match geolocation::gpsd::first_fix(&config.location.gpsd).await {
+            Ok(fix) => {
+                info(
+                    config.silent,
+                    &format!("GPS position: {:.4}, {:.4}", fix.latitude, fix.longitude),
+                );
+                config.location.latitude = fix.latitude;
+                config.location.longitude = fix.longitude;
+                auto_location = false;
+            }
+            Err(e) => {
+                eprintln!("{}", e.user_friendly_message());
+                auto_location = true;
+            }
+        }
     }
 
     // Auto-detect location if enabled
-    if config.location.auto {
+    if auto_location {
         info(config.silent, "Auto-detecting location...");
-        match geolocation::detect_location().await {
+        match geolocation::detect_location(&config).await {
             Ok(geo_loc) => {
                 if let Some(city) = &geo_loc.city {
                     info(
@@ -202,6 +367,27 @@ async fn main() -> io::Result<()> {
         }
     }
 
+    let calendar = match EventCalendar::from_config(&config) {
+        Ok(calendar) => calendar,
+        Err(e) => {
+            eprintln!("Error loading events: {}", e);
+            EventCalendar::new(!config.hide_holidays, Vec::new())
+        }
+    };
+
+    let season = config
+        .season
+        .unwrap_or_else(|| Season::current(config.location.latitude));
+
+    config.apply_season_defaults(season);
+    let theme = match config.scene.build(season) {
+        Ok(theme) => theme,
+        Err(e) => {
+            eprintln!("Error loading scene: {}", e);
+            Box::new(CottageTheme::new(season))
+        }
+    };
+
     let mut renderer = match TerminalRenderer::new() {
         Ok(r) => r,
         Err(e) => {
@@ -216,22 +402,27 @@ async fn main() -> io::Result<()> {
     };
 
     let (term_width, term_height) = renderer.get_size();
+    let scene = WorldScene::new(term_width, term_height, theme, calendar);
 
     let mut app = app::App::new(
         &config,
-        cli.simulate,
+        cli.simulate.clone(),
         cli.night,
-        cli.leaves,
+        scene,
         term_width,
         term_height,
+        cli.fps,
     );
 
-    let result = tokio::select! {
-        res = app.run(&mut renderer) => res,
-        _ = tokio::signal::ctrl_c() => {
-            Ok(())
-        }
-    };
+    // SIGHUP reads the config file again, with the same flags on top
+    let reload_cli = cli.clone();
+    app.set_config_loader(move || {
+        let mut config = Config::load()?;
+        apply_cli_overrides(&reload_cli, &mut config);
+        Ok(config)
+    });
+
+    let result = app.run(&mut renderer).await;
 
     renderer.cleanup()?;
 
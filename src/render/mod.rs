@@ -9,8 +9,12 @@ use crossterm::{
 };
 use std::io::{self, BufWriter, IsTerminal, Stdout, Write};
 
-const MIN_TERMINAL_WIDTH: u16 = 70;
-const MIN_TERMINAL_HEIGHT: u16 = 20;
+// Smallest size the compact scene layout can draw into
+const MIN_TERMINAL_WIDTH: u16 = 40;
+const MIN_TERMINAL_HEIGHT: u16 = 12;
+
+// First row of the toast box, leaving the HUD line above it clear
+const TOAST_ROW: u16 = 3;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 struct Cell {
@@ -176,6 +180,40 @@ impl TerminalRenderer {
         Ok(())
     }
 
+    /// Shows a short boxed message in the top-right corner, below the HUD.
+    pub fn render_toast(&mut self, text: &str, color: Color) -> io::Result<()> {
+        let inner_width = text
+            .chars()
+            .count()
+            .min(self.width.saturating_sub(6) as usize);
+        let lines = boxed("", &[text.to_string()], inner_width);
+        let x = self.width.saturating_sub(inner_width as u16 + 6);
+
+        for (i, line) in lines.iter().enumerate() {
+            self.render_line_colored(x, TOAST_ROW + i as u16, line, color)?;
+        }
+        Ok(())
+    }
+
+    /// Draws a titled box in the middle of the screen, over the scene.
+    /// Lines that do not fit are cut off.
+    pub fn render_overlay(
+        &mut self,
+        title: &str,
+        lines: &[String],
+        color: Color,
+    ) -> io::Result<()> {
+        let max_width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let inner_width = max_width
+            .max(title.chars().count() + 2)
+            .min(self.width.saturating_sub(8) as usize);
+        let visible = lines.len().min(self.height.saturating_sub(6) as usize);
+
+        let boxed = boxed(title, &lines[..visible], inner_width);
+        let start_row = self.height.saturating_sub(boxed.len() as u16) / 2;
+        self.render_centered_colored(&boxed, start_row, color)
+    }
+
     pub fn flash_screen(&mut self) -> io::Result<()> {
         let flash_color = self.capabilities.adjust_color(Color::White);
         for cell in &mut self.buffer {
@@ -231,3 +269,44 @@ impl Drop for TerminalRenderer {
         let _ = self.cleanup();
     }
 }
+
+/// Frames `lines` in an ASCII box `inner_width` columns wide, with `title`
+/// set into the top border. Every line of the result has the same width.
+fn boxed(title: &str, lines: &[String], inner_width: usize) -> Vec<String> {
+    let border_width = inner_width + 2;
+    let title: String = if title.is_empty() {
+        String::new()
+    } else {
+        format!(" {} ", title).chars().take(border_width).collect()
+    };
+
+    let mut boxed = Vec::with_capacity(lines.len() + 2);
+    boxed.push(format!("+{:-<border_width$}+", title));
+    for line in lines {
+        let line: String = line.chars().take(inner_width).collect();
+        boxed.push(format!("| {:<inner_width$} |", line));
+    }
+    boxed.push(format!("+{}+", "-".repeat(border_width)));
+    boxed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boxed_pads_and_truncates() {
+        let lines = vec!["short".to_string(), "much too long".to_string()];
+        let boxed = boxed("Errors", &lines, 8);
+
+        assert_eq!(
+            boxed,
+            vec![
+                "+ Errors --+",
+                "| short    |",
+                "| much too |",
+                "+----------+",
+            ]
+        );
+    }
+}
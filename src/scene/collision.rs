@@ -0,0 +1,116 @@
+/// Occupancy grid of the static scene art. Particle systems query it to find
+/// out whether a cell is covered by the house, the ground or a decoration.
+#[derive(Debug, Clone, Default)]
+pub struct CollisionMask {
+    width: u16,
+    height: u16,
+    cells: Vec<bool>,
+}
+
+impl CollisionMask {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![false; width as usize * height as usize],
+        }
+    }
+
+    pub fn set(&mut self, x: u16, y: u16) {
+        if x < self.width && y < self.height {
+            self.cells[y as usize * self.width as usize + x as usize] = true;
+        }
+    }
+
+    /// Marks every non-space character of `lines` drawn at `(x, y)`. Art
+    /// reaching past the largest coordinate is cut off there.
+    pub fn add_art(&mut self, x: u16, y: u16, lines: &[&str]) {
+        for (i, line) in lines.iter().enumerate() {
+            let Some(row) = u16::try_from(i).ok().and_then(|i| y.checked_add(i)) else {
+                break;
+            };
+            for (j, ch) in line.chars().enumerate() {
+                let Some(col) = u16::try_from(j).ok().and_then(|j| x.checked_add(j)) else {
+                    break;
+                };
+                if ch != ' ' {
+                    self.set(col, row);
+                }
+            }
+        }
+    }
+
+    /// Marks all rows from `y_start` to the bottom of the mask.
+    pub fn add_rows(&mut self, y_start: u16) {
+        for y in y_start..self.height {
+            for x in 0..self.width {
+                self.set(x, y);
+            }
+        }
+    }
+
+    /// Whether the cell containing the point `(x, y)` is occupied.
+    /// Points outside the mask are never solid.
+    pub fn is_solid(&self, x: f32, y: f32) -> bool {
+        if x < 0.0 || y < 0.0 {
+            return false;
+        }
+
+        let (x, y) = (x as usize, y as usize);
+        if x >= self.width as usize || y >= self.height as usize {
+            return false;
+        }
+
+        self.cells[y * self.width as usize + x]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_art_ignores_spaces() {
+        let mut mask = CollisionMask::new(10, 5);
+        mask.add_art(2, 1, &[" # ", "###"]);
+
+        assert!(!mask.is_solid(2.0, 1.0));
+        assert!(mask.is_solid(3.0, 1.0));
+        assert!(mask.is_solid(2.5, 2.9));
+        assert!(!mask.is_solid(5.0, 2.0));
+    }
+
+    #[test]
+    fn test_add_art_with_long_lines() {
+        let mut mask = CollisionMask::new(10, 5);
+        let long = "#".repeat(70_000);
+        mask.add_art(0, 0, &[&long]);
+        assert!(mask.is_solid(9.0, 0.0));
+        assert!(!mask.is_solid(0.0, 1.0));
+
+        mask.add_art(u16::MAX - 1, u16::MAX, &["###", "###"]);
+        mask.add_art(3, 2, &[&long]);
+        assert!(!mask.is_solid(2.0, 2.0));
+        assert!(mask.is_solid(3.0, 2.0));
+    }
+
+    #[test]
+    fn test_add_rows_fills_to_bottom() {
+        let mut mask = CollisionMask::new(4, 6);
+        mask.add_rows(4);
+
+        assert!(!mask.is_solid(0.0, 3.0));
+        assert!(mask.is_solid(0.0, 4.0));
+        assert!(mask.is_solid(3.0, 5.0));
+    }
+
+    #[test]
+    fn test_out_of_bounds_is_not_solid() {
+        let mut mask = CollisionMask::new(4, 4);
+        mask.add_rows(0);
+
+        assert!(!mask.is_solid(-0.5, 1.0));
+        assert!(!mask.is_solid(4.0, 1.0));
+        assert!(!mask.is_solid(1.0, 4.0));
+    }
+}
@@ -1,9 +1,13 @@
 use crate::render::TerminalRenderer;
+use crate::scene::house::House;
+use crate::scene::layout::{self, Prop};
+use crate::scene::season::Season;
 use crossterm::style::Color;
 use std::io;
 
-#[derive(Default)]
-pub struct Decorations;
+pub struct Decorations {
+    season: Season,
+}
 
 pub struct DecorationRenderConfig {
     pub horizon_y: u16,
@@ -13,9 +17,17 @@ pub struct DecorationRenderConfig {
     pub is_day: bool,
 }
 
+/// A piece of decoration art positioned in the scene.
+pub struct Placement {
+    pub x: u16,
+    pub y: u16,
+    pub lines: Vec<&'static str>,
+    pub color: Color,
+}
+
 impl Decorations {
-    pub fn new() -> Self {
-        Self
+    pub fn new(season: Season) -> Self {
+        Self { season }
     }
 
     pub fn render(
@@ -23,111 +35,366 @@ impl Decorations {
         renderer: &mut TerminalRenderer,
         config: &DecorationRenderConfig,
     ) -> io::Result<()> {
-        // Render Tree (Left of house)
-        let (tree_lines, tree_color) = self.get_tree(config.is_day);
-        let tree_height = tree_lines.len() as u16;
-        let tree_y = config.horizon_y.saturating_sub(tree_height);
-        let tree_x = config.house_x.saturating_sub(20);
-
-        if tree_x > 0 {
-            for (i, line) in tree_lines.iter().enumerate() {
+        for placement in self.placements(config) {
+            for (i, line) in placement.lines.iter().enumerate() {
                 for (j, ch) in line.chars().enumerate() {
                     if ch != ' ' {
                         renderer.render_char(
-                            tree_x + j as u16,
-                            tree_y + i as u16,
+                            placement.x + j as u16,
+                            placement.y + i as u16,
                             ch,
-                            tree_color,
+                            placement.color,
                         )?;
                     }
                 }
             }
         }
 
-        // Render Fence (Right of house)
+        Ok(())
+    }
+
+    /// Positions of every decoration for the given layout. Shared by
+    /// rendering and the scene collision mask.
+    pub fn placements(&self, config: &DecorationRenderConfig) -> Vec<Placement> {
+        let mut placements = Vec::with_capacity(4);
+
+        // Tree (Left of house)
+        let (tree_lines, tree_color) = self.get_tree(config.is_day);
+        let tree_height = tree_lines.len() as u16;
+        let tree_y = config.horizon_y.saturating_sub(tree_height);
+        let tree_x = config.house_x.saturating_sub(20);
+
+        if tree_x > 0 {
+            placements.push(Placement {
+                x: tree_x,
+                y: tree_y,
+                lines: tree_lines,
+                color: tree_color,
+            });
+        }
+
+        // Fence (Right of house)
         let (fence_lines, fence_color) = self.get_fence(config.is_day);
-        let fence_height = fence_lines.len() as u16;
-        let fence_y = config.horizon_y.saturating_sub(fence_height); // Sitting on ground
-        let fence_x = config.house_x + config.house_width + 2; // Slight gap
+        let (fence_x, fence_y) = self.fence_position(config);
 
         if fence_x < config.width {
-            for (i, line) in fence_lines.iter().enumerate() {
-                for (j, ch) in line.chars().enumerate() {
-                    if ch != ' ' {
-                        renderer.render_char(
-                            fence_x + j as u16,
-                            fence_y + i as u16,
-                            ch,
-                            fence_color,
-                        )?;
-                    }
-                }
-            }
+            placements.push(Placement {
+                x: fence_x,
+                y: fence_y,
+                lines: fence_lines,
+                color: fence_color,
+            });
         }
 
-        // Render Mailbox (On ground top level, left of tree)
+        // Mailbox (On ground top level, left of tree)
         let (mailbox_lines, mailbox_color) = self.get_mailbox(config.is_day);
         let mailbox_height = mailbox_lines.len() as u16;
         let mailbox_x = tree_x.saturating_sub(10); // Left of tree
         let mailbox_y = config.horizon_y.saturating_sub(mailbox_height); // On ground top
 
         if mailbox_x < config.width {
-            for (i, line) in mailbox_lines.iter().enumerate() {
-                for (j, ch) in line.chars().enumerate() {
-                    if ch != ' ' {
-                        renderer.render_char(
-                            mailbox_x + j as u16,
-                            mailbox_y + i as u16,
-                            ch,
-                            mailbox_color,
-                        )?;
-                    }
-                }
-            }
+            placements.push(Placement {
+                x: mailbox_x,
+                y: mailbox_y,
+                lines: mailbox_lines,
+                color: mailbox_color,
+            });
         }
 
-        // Render Second Tree (Right of house, only if terminal is wide enough)
+        // Second Tree (Right of house, only if terminal is wide enough)
         if config.width > 120 {
             let (pine_lines, pine_color) = self.get_pine_tree(config.is_day);
             let pine_height = pine_lines.len() as u16;
             let pine_x = config.house_x + config.house_width + 18; // 18 chars right of house
             let pine_y = config.horizon_y.saturating_sub(pine_height);
 
+            // Check if full tree fits
             if pine_x + 10 < config.width {
-                // Check if full tree fits
-                for (i, line) in pine_lines.iter().enumerate() {
-                    for (j, ch) in line.chars().enumerate() {
-                        if ch != ' ' {
-                            renderer.render_char(
-                                pine_x + j as u16,
-                                pine_y + i as u16,
-                                ch,
-                                pine_color,
-                            )?;
-                        }
-                    }
-                }
+                placements.push(Placement {
+                    x: pine_x,
+                    y: pine_y,
+                    lines: pine_lines,
+                    color: pine_color,
+                });
             }
         }
 
+        self.fill_spare_width(config, &mut placements);
+        placements
+    }
+
+    /// Adds neighbouring houses, trees and fences to the columns left empty
+    /// on either side of the garden.
+    fn fill_spare_width(&self, config: &DecorationRenderConfig, placements: &mut Vec<Placement>) {
+        let left_edge = placements
+            .iter()
+            .map(|p| p.x)
+            .fold(config.house_x, u16::min);
+        // Leave room for the windsock to stream out beside the fence
+        let right_edge = placements
+            .iter()
+            .map(|p| p.x + p.lines[0].chars().count() as u16)
+            .fold(config.house_x + config.house_width, u16::max)
+            + 6;
+
+        for (offset, prop) in layout::fill_strip(left_edge, 1) {
+            let x = left_edge - offset - prop.width();
+            placements.push(self.prop_placement(prop, x, config));
+        }
+
+        for (offset, prop) in layout::fill_strip(config.width.saturating_sub(right_edge), 2) {
+            placements.push(self.prop_placement(prop, right_edge + offset, config));
+        }
+    }
+
+    fn prop_placement(&self, prop: Prop, x: u16, config: &DecorationRenderConfig) -> Placement {
+        let (lines, color) = match prop {
+            Prop::Cottage => (
+                House::get_small_ascii(),
+                // Neighbours are further back, so they are drawn muted
+                if config.is_day {
+                    Color::Grey
+                } else {
+                    Color::DarkGrey
+                },
+            ),
+            Prop::Tree => self.get_tree(config.is_day),
+            Prop::Pine => self.get_pine_tree(config.is_day),
+            Prop::Fence => self.get_fence(config.is_day),
+        };
+
+        Placement {
+            x,
+            y: config.horizon_y.saturating_sub(lines.len() as u16),
+            lines,
+            color,
+        }
+    }
+
+    fn fence_position(&self, config: &DecorationRenderConfig) -> (u16, u16) {
+        let (fence_lines, _) = self.get_fence(config.is_day);
+        let fence_height = fence_lines.len() as u16;
+        let fence_y = config.horizon_y.saturating_sub(fence_height); // Sitting on ground
+        let fence_x = config.house_x + config.house_width + 2; // Slight gap
+        (fence_x, fence_y)
+    }
+
+    /// Top of the windsock pole, which stands on the last fence post.
+    /// `None` when there is no room for the sock to extend.
+    pub fn windsock_anchor(&self, config: &DecorationRenderConfig) -> Option<(u16, u16)> {
+        let (fence_lines, _) = self.get_fence(config.is_day);
+        let (fence_x, fence_y) = self.fence_position(config);
+        let pole_x = fence_x + fence_lines[0].len() as u16 - 1;
+
+        if pole_x + 6 < config.width && fence_y >= 4 {
+            Some((pole_x, fence_y - 3))
+        } else {
+            None
+        }
+    }
+
+    /// Draws the weathervane with its mast at `(x, y)`. The arrow points
+    /// toward the direction the wind blows from.
+    pub fn render_weathervane(
+        &self,
+        renderer: &mut TerminalRenderer,
+        x: u16,
+        y: u16,
+        wind_direction: f64,
+        is_day: bool,
+    ) -> io::Result<()> {
+        let lines = self.get_weathervane(wind_direction);
+        let color = if is_day {
+            Color::DarkYellow
+        } else {
+            Color::DarkGrey
+        };
+
+        let left = x.saturating_sub(2);
+        for (i, line) in lines.iter().enumerate() {
+            for (j, ch) in line.chars().enumerate() {
+                if ch != ' ' {
+                    renderer.render_char(left + j as u16, y + i as u16, ch, color)?;
+                }
+            }
+        }
         Ok(())
     }
 
-    fn get_tree(&self, is_day: bool) -> (Vec<&'static str>, Color) {
-        (
-            vec![
-                "      ####      ",
-                "    ########    ",
-                "   ##########   ",
-                "    ########    ",
-                "      _||_      ",
-            ],
-            if is_day {
-                Color::DarkGreen
+    /// Draws a windsock hanging from the pole top at `(x, y)`. The sock
+    /// extends further with each Beaufort level and flutters below gale force.
+    #[allow(clippy::too_many_arguments)]
+    pub fn render_windsock(
+        &self,
+        renderer: &mut TerminalRenderer,
+        x: u16,
+        y: u16,
+        beaufort: u8,
+        blows_left: bool,
+        frame: u64,
+        is_day: bool,
+    ) -> io::Result<()> {
+        let pole_color = if is_day { Color::Grey } else { Color::DarkGrey };
+        let (stripe_color, band_color) = if is_day {
+            (Color::Red, Color::White)
+        } else {
+            (Color::DarkRed, Color::Grey)
+        };
+
+        for dy in 0..3 {
+            renderer.render_char(x, y + dy, '|', pole_color)?;
+        }
+
+        for (dx, dy, ch) in self.get_windsock(beaufort, frame) {
+            let (col, ch) = if blows_left {
+                let mirrored = match ch {
+                    '>' => '<',
+                    ')' => '(',
+                    other => other,
+                };
+                (x.checked_sub(dx), mirrored)
             } else {
-                Color::Rgb { r: 0, g: 50, b: 0 }
-            },
-        )
+                (Some(x + dx), ch)
+            };
+
+            if let Some(col) = col {
+                let color = if ch == '-' { band_color } else { stripe_color };
+                renderer.render_char(col, y + dy, ch, color)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn get_weathervane(&self, wind_direction: f64) -> [&'static str; 3] {
+        const ARROWS: [&str; 8] = [
+            "  ^  ", // N
+            "  ^->", // NE
+            "  -->", // E
+            "  v->", // SE
+            "  v  ", // S
+            "<-v  ", // SW
+            "<--  ", // W
+            "<-^  ", // NW
+        ];
+        let index = ((wind_direction.rem_euclid(360.0) + 22.5) / 45.0) as usize % 8;
+        [ARROWS[index], "W-+-E", "  |  "]
+    }
+
+    /// Sock cells as `(dx, dy, char)` offsets from the pole top, extending
+    /// to the right.
+    fn get_windsock(&self, beaufort: u8, frame: u64) -> Vec<(u16, u16, char)> {
+        let length = match beaufort {
+            0 => 0,
+            1..=2 => 1,
+            3..=4 => 2,
+            5..=6 => 3,
+            _ => 4,
+        };
+
+        if length == 0 {
+            // Limp sock hanging down the pole
+            return vec![(1, 0, ')'), (1, 1, ')')];
+        }
+
+        let mut cells: Vec<(u16, u16, char)> = (1..=length)
+            .map(|i| (i, 0, if i % 2 == 1 { '=' } else { '-' }))
+            .collect();
+
+        let fluttering = beaufort < 7 && (frame / 4) % 2 == 1;
+        let tip = length + 1;
+        if fluttering {
+            // Light winds let the tip droop while it flutters
+            let droop = if beaufort <= 2 { 1 } else { 0 };
+            cells.push((tip, droop, '~'));
+        } else {
+            cells.push((tip, 0, '>'));
+        }
+        cells
+    }
+
+    pub fn get_tree(&self, is_day: bool) -> (Vec<&'static str>, Color) {
+        match self.season {
+            Season::Spring => (
+                vec![
+                    "      @*@@      ",
+                    "    @@*@@@*@    ",
+                    "   @*@@@*@@@*   ",
+                    "    @@*@@@@*    ",
+                    "      _||_      ",
+                ],
+                if is_day {
+                    Color::Rgb {
+                        r: 255,
+                        g: 182,
+                        b: 193,
+                    }
+                } else {
+                    Color::Rgb {
+                        r: 120,
+                        g: 80,
+                        b: 90,
+                    }
+                },
+            ),
+            Season::Summer => (
+                vec![
+                    "      ####      ",
+                    "    ########    ",
+                    "   ##########   ",
+                    "    ########    ",
+                    "      _||_      ",
+                ],
+                if is_day {
+                    Color::DarkGreen
+                } else {
+                    Color::Rgb { r: 0, g: 50, b: 0 }
+                },
+            ),
+            Season::Autumn => (
+                vec![
+                    "      %%#       ",
+                    "    #%%##%%     ",
+                    "   %##%%#%%#%   ",
+                    "    #%%##%#     ",
+                    "      _||_      ",
+                ],
+                if is_day {
+                    Color::Rgb {
+                        r: 205,
+                        g: 102,
+                        b: 0,
+                    }
+                } else {
+                    Color::Rgb {
+                        r: 100,
+                        g: 50,
+                        b: 0,
+                    }
+                },
+            ),
+            Season::Winter => (
+                vec![
+                    "     \\  |  /    ",
+                    "    \\ \\ | / /   ",
+                    "     \\_\\|/_/    ",
+                    "       ||       ",
+                    "      _||_      ",
+                ],
+                if is_day {
+                    Color::Rgb {
+                        r: 101,
+                        g: 67,
+                        b: 33,
+                    }
+                } else {
+                    Color::Rgb {
+                        r: 60,
+                        g: 40,
+                        b: 20,
+                    }
+                },
+            ),
+        }
     }
 
     fn get_fence(&self, is_day: bool) -> (Vec<&'static str>, Color) {
@@ -144,7 +411,7 @@ impl Decorations {
         )
     }
 
-    fn get_pine_tree(&self, is_day: bool) -> (Vec<&'static str>, Color) {
+    pub fn get_pine_tree(&self, is_day: bool) -> (Vec<&'static str>, Color) {
         (
             vec![
                 "    *    ",
@@ -161,3 +428,83 @@ impl Decorations {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weathervane_points_into_wind() {
+        let decorations = Decorations::new(Season::Summer);
+        assert_eq!(decorations.get_weathervane(0.0)[0], "  ^  ");
+        assert_eq!(decorations.get_weathervane(350.0)[0], "  ^  ");
+        assert_eq!(decorations.get_weathervane(90.0)[0], "  -->");
+        assert_eq!(decorations.get_weathervane(225.0)[0], "<-v  ");
+        assert_eq!(decorations.get_weathervane(-90.0)[0], "<--  ");
+    }
+
+    #[test]
+    fn test_windsock_extends_with_beaufort() {
+        let decorations = Decorations::new(Season::Summer);
+        let calm = decorations.get_windsock(0, 0);
+        let breeze = decorations.get_windsock(3, 0);
+        let gale = decorations.get_windsock(8, 0);
+
+        assert!(calm.iter().all(|&(dx, _, _)| dx == 1));
+        assert_eq!(breeze.last(), Some(&(3, 0, '>')));
+        assert_eq!(gale.last(), Some(&(5, 0, '>')));
+    }
+
+    #[test]
+    fn test_tree_follows_season() {
+        let spring = Decorations::new(Season::Spring).get_tree(true).0;
+        let winter = Decorations::new(Season::Winter).get_tree(true).0;
+
+        assert!(spring[1].contains('@'));
+        assert!(
+            !winter
+                .iter()
+                .any(|line| line.contains('#') || line.contains('@'))
+        );
+        assert!(winter.iter().all(|line| line.chars().count() == 16));
+    }
+
+    #[test]
+    fn test_wide_terminals_get_extra_props() {
+        let decorations = Decorations::new(Season::Summer);
+        let config = |width: u16| DecorationRenderConfig {
+            horizon_y: 40,
+            house_x: width / 2 - House::WIDTH / 2,
+            house_width: House::WIDTH,
+            width,
+            is_day: true,
+        };
+
+        let standard = decorations.placements(&config(100));
+        let wide = decorations.placements(&config(300));
+
+        assert!(wide.len() > standard.len() + 4);
+        for placement in &wide {
+            let width = placement.lines[0].chars().count() as u16;
+            assert!(placement.x + width <= 300);
+            assert_eq!(placement.y + placement.lines.len() as u16, 40);
+        }
+
+        for prop in [Prop::Cottage, Prop::Tree, Prop::Pine, Prop::Fence] {
+            let placement = decorations.prop_placement(prop, 0, &config(100));
+            assert!(
+                placement
+                    .lines
+                    .iter()
+                    .all(|line| line.chars().count() as u16 == prop.width())
+            );
+        }
+    }
+
+    #[test]
+    fn test_windsock_flutters_below_gale() {
+        let decorations = Decorations::new(Season::Summer);
+        assert_eq!(decorations.get_windsock(3, 4).last(), Some(&(3, 0, '~')));
+        assert_eq!(decorations.get_windsock(8, 4).last(), Some(&(5, 0, '>')));
+    }
+}
@@ -0,0 +1,320 @@
+use crate::config::{Config, EventConfig, expand_home};
+use crate::error::ConfigError;
+use crate::render::TerminalRenderer;
+use chrono::{Datelike, NaiveDate};
+use crossterm::style::Color;
+use std::fs;
+use std::io;
+
+const MAX_ART_LINES: usize = 12;
+const MAX_ART_WIDTH: usize = 40;
+
+/// A day of the year written as `MM-DD`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MonthDay {
+    month: u32,
+    day: u32,
+}
+
+impl MonthDay {
+    pub const fn new(month: u32, day: u32) -> Self {
+        Self { month, day }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        let (month, day) = value.trim().split_once('-')?;
+        let month: u32 = month.parse().ok()?;
+        let day: u32 = day.parse().ok()?;
+
+        // 2024 is a leap year, so Feb 29 is accepted
+        NaiveDate::from_ymd_opt(2024, month, day)?;
+        Some(Self { month, day })
+    }
+
+    fn of(date: NaiveDate) -> Self {
+        Self {
+            month: date.month(),
+            day: date.day(),
+        }
+    }
+}
+
+/// Inclusive range of days. A range whose end comes before its start
+/// wraps over the new year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    start: MonthDay,
+    end: MonthDay,
+}
+
+impl DateRange {
+    pub const fn new(start: MonthDay, end: MonthDay) -> Self {
+        Self { start, end }
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        let day = MonthDay::of(date);
+        if self.start <= self.end {
+            day >= self.start && day <= self.end
+        } else {
+            day >= self.start || day <= self.end
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Holiday {
+    /// String lights along the eaves of the house
+    WinterLights,
+    /// A pumpkin by the front door
+    Halloween,
+    /// Fireworks in the night sky
+    NewYear,
+}
+
+impl Holiday {
+    pub const ALL: [Holiday; 3] = [Holiday::WinterLights, Holiday::Halloween, Holiday::NewYear];
+
+    pub fn dates(self) -> DateRange {
+        match self {
+            Holiday::WinterLights => DateRange::new(MonthDay::new(12, 1), MonthDay::new(1, 6)),
+            Holiday::Halloween => DateRange::new(MonthDay::new(10, 20), MonthDay::new(10, 31)),
+            Holiday::NewYear => DateRange::new(MonthDay::new(12, 31), MonthDay::new(1, 1)),
+        }
+    }
+}
+
+/// A user-defined date range with its own ASCII art.
+#[derive(Debug, Clone)]
+pub struct CustomEvent {
+    pub dates: DateRange,
+    pub art: Vec<String>,
+}
+
+impl CustomEvent {
+    fn from_config(event: &EventConfig) -> Result<Self, ConfigError> {
+        let parse = |value: &str| {
+            MonthDay::parse(value).ok_or_else(|| ConfigError::InvalidEventDate {
+                name: event.name.clone(),
+                value: value.to_string(),
+            })
+        };
+
+        let start = parse(&event.start)?;
+        let end = match &event.end {
+            Some(end) => parse(end)?,
+            None => start,
+        };
+
+        let path = expand_home(&event.art);
+        let content = fs::read_to_string(&path).map_err(|e| ConfigError::ReadError {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+
+        Ok(Self {
+            dates: DateRange::new(start, end),
+            art: parse_art(&content),
+        })
+    }
+}
+
+/// Built-in holidays plus the events defined in the config file.
+#[derive(Debug, Clone, Default)]
+pub struct EventCalendar {
+    holidays: bool,
+    custom: Vec<CustomEvent>,
+}
+
+impl EventCalendar {
+    pub fn new(holidays: bool, custom: Vec<CustomEvent>) -> Self {
+        Self { holidays, custom }
+    }
+
+    /// Reads the `[[events]]` table and loads every art file it points to.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        Self::from_events(!config.hide_holidays, &config.events)
+    }
+
+    /// Loads the art for `events`, with or without the built-in holidays.
+    pub fn from_events(holidays: bool, events: &[EventConfig]) -> Result<Self, ConfigError> {
+        let custom = events
+            .iter()
+            .map(CustomEvent::from_config)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::new(holidays, custom))
+    }
+
+    pub fn holidays_on(&self, date: NaiveDate) -> Vec<Holiday> {
+        if !self.holidays {
+            return Vec::new();
+        }
+
+        Holiday::ALL
+            .into_iter()
+            .filter(|holiday| holiday.dates().contains(date))
+            .collect()
+    }
+
+    pub fn custom_on(&self, date: NaiveDate) -> Vec<CustomEvent> {
+        self.custom
+            .iter()
+            .filter(|event| event.dates.contains(date))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Draws a string of bulbs from `(x, y)` over `length` columns. The
+/// colours chase along the string as `frame` advances.
+pub fn render_string_lights(
+    renderer: &mut TerminalRenderer,
+    x: u16,
+    y: u16,
+    length: u16,
+    frame: u64,
+) -> io::Result<()> {
+    const BULB_COLORS: [Color; 4] = [Color::Red, Color::Green, Color::Yellow, Color::Blue];
+    let shift = (frame / 10) as usize;
+
+    for (i, dx) in (0..length).step_by(3).enumerate() {
+        let color = BULB_COLORS[(i + shift) % BULB_COLORS.len()];
+        renderer.render_char(x + dx, y, 'o', color)?;
+    }
+    Ok(())
+}
+
+pub fn render_pumpkin(
+    renderer: &mut TerminalRenderer,
+    x: u16,
+    y: u16,
+    is_day: bool,
+) -> io::Result<()> {
+    let color = if is_day {
+        Color::Rgb {
+            r: 255,
+            g: 140,
+            b: 0,
+        }
+    } else {
+        Color::Rgb {
+            r: 170,
+            g: 90,
+            b: 0,
+        }
+    };
+
+    for (i, line) in [" _)_ ", "(_^_)"].iter().enumerate() {
+        for (j, ch) in line.chars().enumerate() {
+            if ch != ' ' {
+                renderer.render_char(x + j as u16, y + i as u16, ch, color)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Draws the art of a user-defined event with its top-left corner at `(x, y)`.
+pub fn render_event_art(
+    renderer: &mut TerminalRenderer,
+    x: u16,
+    y: u16,
+    lines: &[String],
+    is_day: bool,
+) -> io::Result<()> {
+    let color = if is_day {
+        Color::Magenta
+    } else {
+        Color::DarkMagenta
+    };
+
+    for (i, line) in lines.iter().enumerate() {
+        for (j, ch) in line.chars().enumerate() {
+            if ch != ' ' {
+                renderer.render_char(x + j as u16, y + i as u16, ch, color)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Trims trailing blank lines and clamps the art to a size that fits
+/// in the sky next to the house.
+fn parse_art(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = content
+        .lines()
+        .take(MAX_ART_LINES)
+        .map(|line| line.trim_end().chars().take(MAX_ART_WIDTH).collect())
+        .collect();
+
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_parse_month_day() {
+        assert_eq!(MonthDay::parse("03-14"), Some(MonthDay::new(3, 14)));
+        assert_eq!(MonthDay::parse("2-29"), Some(MonthDay::new(2, 29)));
+        assert_eq!(MonthDay::parse("13-01"), None);
+        assert_eq!(MonthDay::parse("04-31"), None);
+        assert_eq!(MonthDay::parse("tomorrow"), None);
+    }
+
+    #[test]
+    fn test_range_wraps_over_new_year() {
+        let range = Holiday::WinterLights.dates();
+        assert!(range.contains(date(12, 24)));
+        assert!(range.contains(date(1, 3)));
+        assert!(!range.contains(date(1, 7)));
+        assert!(!range.contains(date(11, 30)));
+    }
+
+    #[test]
+    fn test_builtin_holidays() {
+        let calendar = EventCalendar::new(true, Vec::new());
+
+        assert_eq!(calendar.holidays_on(date(10, 31)), vec![Holiday::Halloween]);
+        assert_eq!(
+            calendar.holidays_on(date(12, 31)),
+            vec![Holiday::WinterLights, Holiday::NewYear]
+        );
+        assert!(calendar.holidays_on(date(7, 4)).is_empty());
+
+        let disabled = EventCalendar::new(false, Vec::new());
+        assert!(disabled.holidays_on(date(12, 31)).is_empty());
+    }
+
+    #[test]
+    fn test_custom_event_dates() {
+        let event = CustomEvent {
+            dates: DateRange::new(MonthDay::new(3, 14), MonthDay::new(3, 14)),
+            art: vec!["cake".to_string()],
+        };
+        let calendar = EventCalendar::new(true, vec![event]);
+
+        assert_eq!(calendar.custom_on(date(3, 14)).len(), 1);
+        assert!(calendar.custom_on(date(3, 15)).is_empty());
+    }
+
+    #[test]
+    fn test_parse_art_trims_and_clamps() {
+        let long_line = "x".repeat(60);
+        let art = parse_art(&format!(" /\\ \n{long_line}\n\n\n"));
+
+        assert_eq!(art.len(), 2);
+        assert_eq!(art[0], " /\\");
+        assert_eq!(art[1].len(), MAX_ART_WIDTH);
+    }
+}
@@ -2,10 +2,178 @@ use crate::render::TerminalRenderer;
 use crossterm::style::Color;
 use std::io;
 
+const PUDDLE_SPACING: usize = 23;
+const MAX_PUDDLE_WIDTH: u16 = 9;
+const MAX_RIPPLES_PER_PUDDLE: usize = 3;
+const RIPPLE_LIFETIME: u8 = 4;
+const WETTING_RATE: f32 = 0.01;
+const DRYING_RATE: f32 = 0.0015;
+const PUDDLE_DRYING_RATE: f32 = 0.002;
+
+// Simple seeded RNG for deterministic decoration positions
+fn pseudo_rand(x: usize, y: usize) -> u32 {
+    ((x as u32 ^ 0x5DEECE6).wrapping_mul(y as u32 ^ 0xB)) % 100
+}
+
+struct Ripple {
+    x: u16,
+    age: u8,
+}
+
+struct Puddle {
+    x: u16,
+    max_width: u16,
+    growth: f32,
+    fill: f32,
+    ripples: Vec<Ripple>,
+}
+
+impl Puddle {
+    fn new(x: u16, seed: u32) -> Self {
+        Self {
+            x,
+            max_width: 4 + (seed % (MAX_PUDDLE_WIDTH as u32 - 3)) as u16,
+            growth: 0.5 + (seed as f32 / 200.0),
+            fill: 0.0,
+            ripples: Vec::with_capacity(MAX_RIPPLES_PER_PUDDLE),
+        }
+    }
+
+    fn width(&self) -> u16 {
+        if self.fill < 0.1 {
+            0
+        } else {
+            ((self.max_width as f32 * self.fill).ceil() as u16).min(self.max_width)
+        }
+    }
+
+    fn span(&self) -> (u16, u16) {
+        let width = self.width();
+        let start = self.x.saturating_sub(width / 2);
+        (start, start + width)
+    }
+
+    fn contains(&self, x: u16) -> bool {
+        let (start, end) = self.span();
+        x >= start && x < end
+    }
+
+    fn char_at(&self, x: u16) -> (char, bool) {
+        for ripple in &self.ripples {
+            let distance = x.abs_diff(ripple.x);
+            if ripple.age == 0 && distance == 0 {
+                return ('o', true);
+            }
+            if ripple.age > 0 && distance == ripple.age as u16 {
+                return (if x < ripple.x { '(' } else { ')' }, true);
+            }
+        }
+        ('~', false)
+    }
+}
+
+/// Surface drawn in the ground strip, chosen by the scene theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroundStyle {
+    #[default]
+    Grass,
+    Pavement,
+    Sand,
+    /// The theme draws its own floor; the ground strip stays empty
+    Indoor,
+}
+
+impl GroundStyle {
+    /// Sand soaks up rain and indoor floors stay dry
+    fn collects_puddles(self) -> bool {
+        matches!(self, GroundStyle::Grass | GroundStyle::Pavement)
+    }
+}
+
+/// Ground strip below the horizon. Tracks how wet the soil is and the
+/// puddles that collect on the surface while it rains.
 #[derive(Default)]
-pub struct Ground;
+pub struct Ground {
+    style: GroundStyle,
+    wetness: f32,
+    puddles: Vec<Puddle>,
+    width: u16,
+}
 
 impl Ground {
+    pub fn new(style: GroundStyle) -> Self {
+        Self {
+            style,
+            ..Self::default()
+        }
+    }
+
+    pub fn update(&mut self, width: u16, is_raining: bool, precipitation: f64, impacts: &[u16]) {
+        if width != self.width {
+            self.layout_puddles(width);
+        }
+
+        if is_raining {
+            self.wetness = (self.wetness + WETTING_RATE).min(1.0);
+            // Heavier precipitation (mm/h) fills puddles faster
+            let rate = 0.002 + (precipitation.max(0.0) as f32 * 0.0015);
+            for puddle in &mut self.puddles {
+                puddle.fill = (puddle.fill + rate * puddle.growth).min(1.0);
+            }
+        } else {
+            self.wetness = (self.wetness - DRYING_RATE).max(0.0);
+            for puddle in &mut self.puddles {
+                puddle.fill = (puddle.fill - PUDDLE_DRYING_RATE).max(0.0);
+            }
+        }
+
+        for puddle in &mut self.puddles {
+            puddle.ripples.retain_mut(|ripple| {
+                ripple.age += 1;
+                ripple.age < RIPPLE_LIFETIME
+            });
+
+            if !is_raining {
+                continue;
+            }
+
+            for &x in impacts {
+                if puddle.ripples.len() < MAX_RIPPLES_PER_PUDDLE && puddle.contains(x) {
+                    puddle.ripples.push(Ripple { x, age: 0 });
+                }
+            }
+        }
+    }
+
+    fn layout_puddles(&mut self, width: u16) {
+        let previous_fill = if self.puddles.is_empty() {
+            0.0
+        } else {
+            self.puddles.iter().map(|p| p.fill).sum::<f32>() / self.puddles.len() as f32
+        };
+
+        let count = if self.style.collects_puddles() {
+            width as usize / PUDDLE_SPACING
+        } else {
+            0
+        };
+
+        self.puddles = (0..count)
+            .map(|i| {
+                let seed = pseudo_rand(i + 7, 3);
+                let x = (i * PUDDLE_SPACING) as u16 + 6 + (seed % 10) as u16;
+                let mut puddle = Puddle::new(x, seed);
+                puddle.fill = previous_fill;
+                puddle
+            })
+            .collect();
+        self.width = width;
+    }
+
+    fn puddle_at(&self, x: u16) -> Option<&Puddle> {
+        self.puddles.iter().find(|p| p.contains(x))
+    }
+
     pub fn render(
         &self,
         renderer: &mut TerminalRenderer,
@@ -14,6 +182,10 @@ impl Ground {
         y_start: u16,
         is_day: bool,
     ) -> io::Result<()> {
+        if self.style == GroundStyle::Indoor {
+            return Ok(());
+        }
+
         let width = width as usize;
         let height = height as usize;
 
@@ -48,14 +220,28 @@ impl Ground {
             }
         };
 
-        // Simple seeded RNG for deterministic decoration positions
-        fn pseudo_rand(x: usize, y: usize) -> u32 {
-            ((x as u32 ^ 0x5DEECE6).wrapping_mul(y as u32 ^ 0xB)) % 100
-        }
+        let (puddle_color, ripple_color) = if is_day {
+            (Color::Blue, Color::Cyan)
+        } else {
+            (Color::DarkBlue, Color::Blue)
+        };
 
         for y in 0..height {
             for x in 0..width {
-                let (ch, color) = if y == 0 {
+                if y == 0
+                    && let Some(puddle) = self.puddle_at(x as u16)
+                {
+                    let (ch, rippling) = puddle.char_at(x as u16);
+                    let color = if rippling { ripple_color } else { puddle_color };
+                    renderer.render_char(x as u16, y_start, ch, color)?;
+                    continue;
+                }
+
+                let (ch, color) = if self.style == GroundStyle::Pavement {
+                    Self::pavement_cell(x, y, is_day)
+                } else if self.style == GroundStyle::Sand {
+                    Self::sand_cell(x, y, is_day)
+                } else if y == 0 {
                     // Top layer: Grass/Flowers only (no path)
                     let r = pseudo_rand(x, y);
                     if r < 5 {
@@ -81,9 +267,185 @@ impl Ground {
                     (ch, soil_color)
                 };
 
-                renderer.render_char(x as u16, y_start + y as u16, ch, color)?;
+                renderer.render_char(
+                    x as u16,
+                    y_start + y as u16,
+                    ch,
+                    darken(color, self.wetness),
+                )?;
             }
         }
         Ok(())
     }
+
+    fn pavement_cell(x: usize, y: usize, is_day: bool) -> (char, Color) {
+        let (kerb, asphalt, marking) = if is_day {
+            (Color::Grey, Color::DarkGrey, Color::White)
+        } else {
+            (
+                Color::DarkGrey,
+                Color::Rgb {
+                    r: 40,
+                    g: 40,
+                    b: 40,
+                },
+                Color::Grey,
+            )
+        };
+
+        match y {
+            0 => ('=', kerb),
+            // Dashed centre line of the road
+            3 if x % 8 < 4 => ('-', marking),
+            _ if pseudo_rand(x, y) < 8 => ('.', asphalt),
+            _ => (' ', asphalt),
+        }
+    }
+
+    fn sand_cell(x: usize, y: usize, is_day: bool) -> (char, Color) {
+        let sand = if is_day {
+            Color::Rgb {
+                r: 230,
+                g: 200,
+                b: 140,
+            }
+        } else {
+            Color::Rgb {
+                r: 120,
+                g: 100,
+                b: 70,
+            }
+        };
+
+        let r = pseudo_rand(x, y);
+        let ch = if r < 3 && y > 0 {
+            '@' // Shell
+        } else if r < 30 {
+            '.'
+        } else if r < 40 {
+            ','
+        } else if y == 0 {
+            '_'
+        } else {
+            ' '
+        };
+        (ch, sand)
+    }
+}
+
+/// Darkens a color to show soaked ground. `amount` ranges from 0.0 (dry)
+/// to 1.0 (fully soaked).
+fn darken(color: Color, amount: f32) -> Color {
+    if amount <= 0.05 {
+        return color;
+    }
+
+    match color {
+        Color::Rgb { r, g, b } => {
+            let factor = 1.0 - amount * 0.45;
+            Color::Rgb {
+                r: (r as f32 * factor) as u8,
+                g: (g as f32 * factor) as u8,
+                b: (b as f32 * factor) as u8,
+            }
+        }
+        _ if amount < 0.5 => color,
+        Color::Green => Color::DarkGreen,
+        Color::DarkGreen => Color::Rgb { r: 0, g: 70, b: 0 },
+        Color::Magenta => Color::DarkMagenta,
+        Color::Red => Color::DarkRed,
+        Color::Cyan => Color::DarkCyan,
+        Color::Yellow => Color::DarkYellow,
+        Color::Blue => Color::DarkBlue,
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puddles_grow_while_raining() {
+        let mut ground = Ground::default();
+        for _ in 0..200 {
+            ground.update(120, true, 5.0, &[]);
+        }
+
+        assert!(ground.wetness > 0.9);
+        assert!(!ground.puddles.is_empty());
+        assert!(ground.puddles.iter().all(|p| p.width() > 0));
+    }
+
+    #[test]
+    fn test_heavier_rain_fills_puddles_faster() {
+        let mut drizzle = Ground::default();
+        let mut downpour = Ground::default();
+        for _ in 0..50 {
+            drizzle.update(120, true, 0.2, &[]);
+            downpour.update(120, true, 8.0, &[]);
+        }
+
+        assert!(downpour.puddles[0].fill > drizzle.puddles[0].fill);
+    }
+
+    #[test]
+    fn test_ground_dries_after_rain() {
+        let mut ground = Ground::default();
+        for _ in 0..200 {
+            ground.update(120, true, 5.0, &[]);
+        }
+        for _ in 0..2000 {
+            ground.update(120, false, 0.0, &[]);
+        }
+
+        assert_eq!(ground.wetness, 0.0);
+        assert!(ground.puddles.iter().all(|p| p.width() == 0));
+    }
+
+    #[test]
+    fn test_impact_creates_ripple() {
+        let mut ground = Ground::default();
+        for _ in 0..200 {
+            ground.update(120, true, 5.0, &[]);
+        }
+
+        let x = ground.puddles[0].x;
+        ground.update(120, true, 5.0, &[x]);
+        assert_eq!(ground.puddles[0].char_at(x), ('o', true));
+
+        ground.update(120, true, 5.0, &[]);
+        assert_eq!(ground.puddles[0].char_at(x + 1), (')', true));
+    }
+
+    #[test]
+    fn test_sand_does_not_collect_puddles() {
+        let mut ground = Ground::new(GroundStyle::Sand);
+        for _ in 0..200 {
+            ground.update(120, true, 5.0, &[]);
+        }
+
+        assert!(ground.puddles.is_empty());
+    }
+
+    #[test]
+    fn test_darken_wet_colors() {
+        assert_eq!(darken(Color::Green, 0.0), Color::Green);
+        assert_eq!(darken(Color::Green, 1.0), Color::DarkGreen);
+        assert_eq!(
+            darken(
+                Color::Rgb {
+                    r: 100,
+                    g: 100,
+                    b: 100
+                },
+                1.0
+            ),
+            Color::Rgb {
+                r: 55,
+                g: 55,
+                b: 55
+            }
+        );
+    }
 }
@@ -20,6 +20,18 @@ impl House {
     pub const WIDTH: u16 = 64;
     pub const HEIGHT: u16 = 13;
     pub const CHIMNEY_X_OFFSET: u16 = 10;
+    pub const RIDGE_X_OFFSET: u16 = 17;
+    pub const DOOR_X_OFFSET: u16 = 16;
+    /// Row of the `~~~` eaves under the roof and where they start and end
+    pub const EAVE_ROW: u16 = 7;
+    pub const EAVE_X_OFFSET: u16 = 3;
+    pub const EAVE_WIDTH: u16 = 29;
+
+    /// Dimensions of the small cottage used by compact layouts
+    pub const SMALL_WIDTH: u16 = 13;
+    pub const SMALL_HEIGHT: u16 = 7;
+    pub const SMALL_CHIMNEY_X_OFFSET: u16 = 2;
+    pub const SMALL_EAVE_ROW: u16 = 3;
 
     pub fn height(&self) -> u16 {
         Self::HEIGHT
@@ -29,6 +41,12 @@ impl House {
         Self::WIDTH
     }
 
+    /// Whether the character at `row` of the art is part of the building.
+    /// The puffs drawn above the chimney are smoke, not solid.
+    pub fn is_solid(row: usize, ch: char) -> bool {
+        ch != ' ' && !(row < 4 && (ch == '(' || ch == ')'))
+    }
+
     pub fn get_ascii(&self) -> Vec<&'static str> {
         vec![
             "          (                  ",
@@ -47,15 +65,22 @@ impl House {
         ]
     }
 
-    pub fn render(
-        &self,
-        renderer: &mut TerminalRenderer,
-        x: u16,
-        y: u16,
-        is_day: bool,
-    ) -> io::Result<()> {
-        let ascii = self.get_ascii();
+    /// A small cottage that fits narrow terminals, and doubles as the
+    /// neighbouring houses on wide ones.
+    pub fn get_small_ascii() -> Vec<&'static str> {
+        vec![
+            "  _          ",
+            " | |_______  ",
+            " /         \\ ",
+            "/___________\\",
+            " | []   [] | ",
+            " |   .-.   | ",
+            " |___| |___| ",
+        ]
+    }
 
+    /// Wood, roof and window colours
+    fn palette(is_day: bool) -> (Color, Color, Color) {
         let wood_color = if is_day {
             WOOD_COLOR
         } else {
@@ -71,6 +96,49 @@ impl House {
             Color::DarkMagenta
         };
         let window_color = if is_day { Color::Cyan } else { Color::Yellow };
+        (wood_color, roof_color, window_color)
+    }
+
+    pub fn render_small(
+        &self,
+        renderer: &mut TerminalRenderer,
+        x: u16,
+        y: u16,
+        is_day: bool,
+    ) -> io::Result<()> {
+        let (wood_color, roof_color, window_color) = Self::palette(is_day);
+
+        for (i, line) in Self::get_small_ascii().iter().enumerate() {
+            for (j, ch) in line.chars().enumerate() {
+                if ch == ' ' {
+                    continue;
+                }
+                let color = if i < 2 && j < 4 {
+                    Color::Grey
+                } else if i <= Self::SMALL_EAVE_ROW as usize {
+                    roof_color
+                } else if ch == '[' || ch == ']' {
+                    window_color
+                } else if ch == '.' || ch == '-' {
+                    DOOR_COLOR
+                } else {
+                    wood_color
+                };
+                renderer.render_char(x + j as u16, y + i as u16, ch, color)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn render(
+        &self,
+        renderer: &mut TerminalRenderer,
+        x: u16,
+        y: u16,
+        is_day: bool,
+    ) -> io::Result<()> {
+        let ascii = self.get_ascii();
+        let (wood_color, roof_color, window_color) = Self::palette(is_day);
 
         for (i, line) in ascii.iter().enumerate() {
             let row = y + i as u16;
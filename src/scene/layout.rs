@@ -0,0 +1,135 @@
+/// Smallest terminal that fits the full house with its ground strip.
+/// Anything smaller falls back to the compact layout.
+pub const STANDARD_MIN_WIDTH: u16 = 70;
+pub const STANDARD_MIN_HEIGHT: u16 = 20;
+
+/// Rows below the horizon that belong to the ground.
+pub const GROUND_HEIGHT: u16 = 7;
+/// Ground rows in compact terminals, leaving most of the height to the sky
+pub const COMPACT_GROUND_HEIGHT: u16 = 3;
+
+/// Columns left free between props placed along a strip
+const MIN_GAP: u16 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutMode {
+    /// A small house on its own, without decorations
+    Compact,
+    /// The full scene, with extra props filling any spare width
+    Standard,
+}
+
+impl LayoutMode {
+    pub fn for_size(width: u16, height: u16) -> Self {
+        if width < STANDARD_MIN_WIDTH || height < STANDARD_MIN_HEIGHT {
+            LayoutMode::Compact
+        } else {
+            LayoutMode::Standard
+        }
+    }
+}
+
+/// Number of ground rows for a terminal `height` rows tall.
+pub fn ground_height(height: u16) -> u16 {
+    if height < STANDARD_MIN_HEIGHT {
+        COMPACT_GROUND_HEIGHT
+    } else {
+        GROUND_HEIGHT
+    }
+}
+
+/// Scenery used to fill the space either side of the main house.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Prop {
+    Cottage,
+    Tree,
+    Pine,
+    Fence,
+}
+
+impl Prop {
+    pub fn width(self) -> u16 {
+        match self {
+            Prop::Cottage => 13,
+            Prop::Tree => 16,
+            Prop::Pine => 9,
+            Prop::Fence => 13,
+        }
+    }
+}
+
+// Simple seeded RNG so the props stay put between frames
+fn pseudo_rand(x: u32, y: u32) -> u32 {
+    (x ^ 0x2545F491)
+        .wrapping_mul(y ^ 0x9E37)
+        .wrapping_add(x.wrapping_mul(17))
+        % 100
+}
+
+/// Lays out props along a strip `length` columns long. Each prop is
+/// returned with the distance of its near edge from the start of the strip,
+/// so callers can grow the strip in either direction away from the house.
+pub fn fill_strip(length: u16, seed: u32) -> Vec<(u16, Prop)> {
+    let mut props = Vec::new();
+    let mut offset = MIN_GAP;
+    let mut i = 0;
+
+    loop {
+        let roll = pseudo_rand(i + 1, seed);
+        let preferred = match roll {
+            0..30 => Prop::Cottage,
+            30..60 => Prop::Tree,
+            60..80 => Prop::Pine,
+            _ => Prop::Fence,
+        };
+
+        // Squeeze a pine into the last gap if the preferred prop is too wide
+        let prop = [preferred, Prop::Pine]
+            .into_iter()
+            .find(|prop| offset + prop.width() <= length);
+        let Some(prop) = prop else {
+            break;
+        };
+
+        props.push((offset, prop));
+        offset += prop.width() + MIN_GAP + (roll % 5) as u16;
+        i += 1;
+    }
+
+    props
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_mode_for_size() {
+        assert_eq!(LayoutMode::for_size(40, 12), LayoutMode::Compact);
+        assert_eq!(LayoutMode::for_size(200, 19), LayoutMode::Compact);
+        assert_eq!(LayoutMode::for_size(69, 50), LayoutMode::Compact);
+        assert_eq!(LayoutMode::for_size(70, 20), LayoutMode::Standard);
+        assert_eq!(ground_height(12), COMPACT_GROUND_HEIGHT);
+        assert_eq!(ground_height(20), GROUND_HEIGHT);
+    }
+
+    #[test]
+    fn test_fill_strip_stays_inside_without_overlap() {
+        for seed in 0..20 {
+            let props = fill_strip(150, seed);
+
+            assert!(props.len() >= 6, "seed {seed}");
+            for pair in props.windows(2) {
+                assert!(pair[0].0 + pair[0].1.width() + MIN_GAP <= pair[1].0);
+            }
+            let (last, prop) = props[props.len() - 1];
+            assert!(last + prop.width() <= 150);
+        }
+    }
+
+    #[test]
+    fn test_fill_strip_is_stable() {
+        assert_eq!(fill_strip(80, 3), fill_strip(80, 3));
+        assert!(fill_strip(MIN_GAP + 8, 1).is_empty());
+    }
+}
@@ -1,81 +1,174 @@
+pub mod collision;
 pub mod decorations;
+pub mod events;
 pub mod ground;
 pub mod house;
+pub mod layout;
+pub mod season;
+pub mod themes;
 
 use crate::render::TerminalRenderer;
-use crate::weather::WeatherConditions;
+use crate::weather::{WeatherConditions, WindConditions};
+use chrono::NaiveDate;
+use collision::CollisionMask;
+use events::{CustomEvent, EventCalendar, Holiday};
 use std::io;
+use themes::{SceneAnchors, SceneTheme, ThemeContext};
 
 pub struct WorldScene {
-    house: house::House,
+    theme: Box<dyn SceneTheme>,
     ground: ground::Ground,
-    decorations: decorations::Decorations,
+    anchors: SceneAnchors,
+    collision_mask: CollisionMask,
+    calendar: EventCalendar,
+    date: Option<NaiveDate>,
+    holidays: Vec<Holiday>,
+    custom_events: Vec<CustomEvent>,
+    frame: u64,
     width: u16,
     height: u16,
 }
 
 impl WorldScene {
-    pub const GROUND_HEIGHT: u16 = 7;
+    pub fn new(
+        width: u16,
+        height: u16,
+        theme: Box<dyn SceneTheme>,
+        calendar: EventCalendar,
+    ) -> Self {
+        let ground = ground::Ground::new(theme.ground_style());
 
-    pub fn new(width: u16, height: u16) -> Self {
-        let house = house::House;
-        let ground = ground::Ground;
-        let decorations = decorations::Decorations::new();
-
-        Self {
-            house,
+        let mut scene = Self {
+            theme,
             ground,
-            decorations,
+            anchors: SceneAnchors::default(),
+            collision_mask: CollisionMask::default(),
+            calendar,
+            date: None,
+            holidays: Vec::new(),
+            custom_events: Vec::new(),
+            frame: 0,
             width,
             height,
-        }
+        };
+        scene.rebuild_layout();
+        scene
     }
 
     pub fn update_size(&mut self, width: u16, height: u16) {
-        self.width = width;
-        self.height = height;
+        if width != self.width || height != self.height {
+            self.width = width;
+            self.height = height;
+            self.rebuild_layout();
+        }
+    }
+
+    /// Looks up the events for `date`. Cheap to call every frame, the
+    /// calendar is only consulted when the day changes. Returns whether it did.
+    pub fn set_date(&mut self, date: NaiveDate) -> bool {
+        if self.date == Some(date) {
+            return false;
+        }
+        self.holidays = self.calendar.holidays_on(date);
+        self.custom_events = self.calendar.custom_on(date);
+        self.date = Some(date);
+        true
+    }
+
+    pub fn has_holiday(&self, holiday: Holiday) -> bool {
+        self.holidays.contains(&holiday)
+    }
+
+    /// Holidays falling on the current date.
+    pub fn holidays(&self) -> &[Holiday] {
+        &self.holidays
+    }
+
+    /// Cells covered by the theme's art and the ground.
+    pub fn collision_mask(&self) -> &CollisionMask {
+        &self.collision_mask
+    }
+
+    /// Horizon and emitter positions for the current terminal size.
+    pub fn anchors(&self) -> &SceneAnchors {
+        &self.anchors
+    }
+
+    fn rebuild_layout(&mut self) {
+        self.anchors = self.theme.anchors(self.width, self.height);
+
+        let mut mask = CollisionMask::new(self.width, self.height);
+        mask.add_rows(self.anchors.horizon_y);
+        self.theme.add_collision(&mut mask, self.width, self.height);
+        self.collision_mask = mask;
+    }
+
+    /// Advances ground wetness and puddles. `impacts` are the columns where
+    /// raindrops reached the ground surface this frame.
+    pub fn update(&mut self, conditions: &WeatherConditions, precipitation: f64, impacts: &[u16]) {
+        self.frame = self.frame.wrapping_add(1);
+        let is_raining = conditions.is_raining || conditions.is_thunderstorm;
+        self.ground
+            .update(self.width, is_raining, precipitation, impacts);
     }
 
     pub fn render(
         &self,
         renderer: &mut TerminalRenderer,
         conditions: &WeatherConditions,
+        wind: &WindConditions,
     ) -> io::Result<()> {
-        let horizon_y = self.height.saturating_sub(Self::GROUND_HEIGHT);
+        let horizon_y = self.anchors.horizon_y;
 
-        // House position
-        let house_width = self.house.width();
-        let house_height = self.house.height();
-        let house_x = (self.width / 2).saturating_sub(house_width / 2);
-        let house_y = horizon_y.saturating_sub(house_height);
-
-        // Door/Path alignment
-
-        // Render Ground
         self.ground.render(
             renderer,
             self.width,
-            Self::GROUND_HEIGHT,
+            self.height.saturating_sub(horizon_y),
             horizon_y,
             conditions.is_day,
         )?;
 
-        // Render House
-        self.house
-            .render(renderer, house_x, house_y, conditions.is_day)?;
-
-        // Render Decorations
-        self.decorations.render(
+        self.theme.render(
             renderer,
-            &crate::scene::decorations::DecorationRenderConfig {
-                horizon_y,
-                house_x,
-                house_width,
+            &ThemeContext {
                 width: self.width,
+                height: self.height,
                 is_day: conditions.is_day,
+                frame: self.frame,
+                wind,
             },
         )?;
 
+        self.render_events(renderer, conditions.is_day)
+    }
+
+    fn render_events(&self, renderer: &mut TerminalRenderer, is_day: bool) -> io::Result<()> {
+        if self.has_holiday(Holiday::WinterLights)
+            && let Some((x, y, length)) = self.anchors.lights
+        {
+            events::render_string_lights(renderer, x, y, length, self.frame)?;
+        }
+
+        if self.has_holiday(Holiday::Halloween)
+            && let Some((x, y)) = self.anchors.porch
+        {
+            events::render_pumpkin(renderer, x, y, is_day)?;
+        }
+
+        // User events are stacked in the sky on the right side
+        let mut y = 3;
+        for event in &self.custom_events {
+            let art_width = event
+                .art
+                .iter()
+                .map(|line| line.chars().count())
+                .max()
+                .unwrap_or(0) as u16;
+            let x = self.width.saturating_sub(art_width + 3);
+            events::render_event_art(renderer, x, y, &event.art, is_day)?;
+            y += event.art.len() as u16 + 1;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,74 @@
+use chrono::{Datelike, NaiveDate};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    /// Meteorological season for `date` at the given latitude. The southern
+    /// hemisphere (negative latitude) is six months out of phase with the north.
+    pub fn from_date(date: NaiveDate, latitude: f64) -> Self {
+        let northern = match date.month() {
+            3..=5 => Season::Spring,
+            6..=8 => Season::Summer,
+            9..=11 => Season::Autumn,
+            _ => Season::Winter,
+        };
+
+        if latitude < 0.0 {
+            northern.opposite()
+        } else {
+            northern
+        }
+    }
+
+    pub fn current(latitude: f64) -> Self {
+        Self::from_date(chrono::Local::now().date_naive(), latitude)
+    }
+
+    fn opposite(self) -> Self {
+        match self {
+            Season::Spring => Season::Autumn,
+            Season::Summer => Season::Winter,
+            Season::Autumn => Season::Spring,
+            Season::Winter => Season::Summer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_northern_hemisphere_seasons() {
+        assert_eq!(Season::from_date(date(1, 15), 52.52), Season::Winter);
+        assert_eq!(Season::from_date(date(4, 1), 52.52), Season::Spring);
+        assert_eq!(Season::from_date(date(7, 20), 52.52), Season::Summer);
+        assert_eq!(Season::from_date(date(10, 31), 52.52), Season::Autumn);
+        assert_eq!(Season::from_date(date(12, 1), 52.52), Season::Winter);
+    }
+
+    #[test]
+    fn test_southern_hemisphere_seasons() {
+        assert_eq!(Season::from_date(date(1, 15), -33.87), Season::Summer);
+        assert_eq!(Season::from_date(date(4, 1), -33.87), Season::Autumn);
+        assert_eq!(Season::from_date(date(7, 20), -33.87), Season::Winter);
+        assert_eq!(Season::from_date(date(10, 31), -33.87), Season::Spring);
+    }
+
+    #[test]
+    fn test_equator_uses_northern_calendar() {
+        assert_eq!(Season::from_date(date(7, 1), 0.0), Season::Summer);
+    }
+}
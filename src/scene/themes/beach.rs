@@ -0,0 +1,131 @@
+use super::{SceneAnchors, SceneTheme, ThemeContext, render_art, render_campfire};
+use crate::render::TerminalRenderer;
+use crate::scene::collision::CollisionMask;
+use crate::scene::ground::GroundStyle;
+use crossterm::style::Color;
+use std::io;
+
+const SEA_ROWS: u16 = 3;
+const PALM_WIDTH: u16 = 15;
+
+/// Sand, a palm tree and a campfire in front of a rolling sea.
+pub struct BeachTheme;
+
+impl BeachTheme {
+    fn get_palm(&self) -> Vec<&'static str> {
+        vec![
+            "  __ _.-._ __  ",
+            " /  `\\ | /`  \\ ",
+            "      \\|/      ",
+            "       )       ",
+            "      (        ",
+            "       )       ",
+            "      (        ",
+            "      _)_      ",
+        ]
+    }
+
+    fn palm_position(&self, width: u16, horizon_y: u16) -> (u16, u16) {
+        let x = (width / 4).saturating_sub(PALM_WIDTH / 2);
+        let y = horizon_y.saturating_sub(self.get_palm().len() as u16);
+        (x, y)
+    }
+
+    fn campfire_position(&self, width: u16, horizon_y: u16) -> (u16, u16) {
+        (width / 2 + width / 6, horizon_y.saturating_sub(1))
+    }
+
+    /// Wave character for a sea cell. Each row scrolls at its own speed so
+    /// the swell appears to roll toward the shore.
+    fn wave_char(x: u16, row: u16, frame: u64) -> char {
+        let phase = (x as u64 + frame / (2 + row as u64)) % 12;
+        match phase {
+            0 => '^',
+            1 | 2 => '~',
+            6 => '-',
+            _ => ' ',
+        }
+    }
+}
+
+impl SceneTheme for BeachTheme {
+    fn ground_style(&self) -> GroundStyle {
+        GroundStyle::Sand
+    }
+
+    fn anchors(&self, width: u16, height: u16) -> SceneAnchors {
+        let horizon_y = self.horizon_y(height);
+        let (fire_x, fire_y) = self.campfire_position(width, horizon_y);
+
+        SceneAnchors {
+            horizon_y,
+            // Smoke rises from the middle of the fire, above the flames
+            emitters: vec![(fire_x + 2, fire_y.saturating_sub(2))],
+            lights: None,
+            porch: Some((fire_x + 8, horizon_y.saturating_sub(2))),
+        }
+    }
+
+    fn add_collision(&self, mask: &mut CollisionMask, width: u16, height: u16) {
+        let horizon_y = self.horizon_y(height);
+        let (x, y) = self.palm_position(width, horizon_y);
+        mask.add_art(x, y, &self.get_palm());
+    }
+
+    fn render(&self, renderer: &mut TerminalRenderer, ctx: &ThemeContext) -> io::Result<()> {
+        let horizon_y = self.horizon_y(ctx.height);
+
+        let (sea_color, crest_color) = if ctx.is_day {
+            (Color::Blue, Color::Cyan)
+        } else {
+            (Color::DarkBlue, Color::Blue)
+        };
+
+        for row in 0..SEA_ROWS {
+            let y = horizon_y.saturating_sub(SEA_ROWS - row);
+            for x in 0..ctx.width {
+                let ch = Self::wave_char(x, row, ctx.frame);
+                if ch != ' ' {
+                    let color = if ch == '^' { crest_color } else { sea_color };
+                    renderer.render_char(x, y, ch, color)?;
+                }
+            }
+        }
+
+        let (palm_x, palm_y) = self.palm_position(ctx.width, horizon_y);
+        let palm = self.get_palm();
+        let leaf_color = if ctx.is_day {
+            Color::Green
+        } else {
+            Color::DarkGreen
+        };
+        let trunk_color = if ctx.is_day {
+            Color::DarkYellow
+        } else {
+            Color::Rgb {
+                r: 90,
+                g: 70,
+                b: 30,
+            }
+        };
+        render_art(renderer, palm_x, palm_y, &palm[..3], leaf_color)?;
+        render_art(renderer, palm_x, palm_y + 3, &palm[3..], trunk_color)?;
+
+        let (fire_x, fire_y) = self.campfire_position(ctx.width, horizon_y);
+        render_campfire(renderer, fire_x, fire_y, ctx.frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_waves_move_with_frames() {
+        let row: String = (0..24).map(|x| BeachTheme::wave_char(x, 0, 0)).collect();
+        let later: String = (0..24).map(|x| BeachTheme::wave_char(x, 0, 2)).collect();
+
+        assert!(row.contains('^'));
+        assert_ne!(row, later);
+    }
+}
@@ -0,0 +1,195 @@
+use super::{SceneAnchors, SceneTheme, ThemeContext, render_art};
+use crate::render::TerminalRenderer;
+use crate::scene::collision::CollisionMask;
+use crate::scene::decorations::Decorations;
+use crate::scene::season::Season;
+use crossterm::style::Color;
+use std::io;
+
+const CABIN_WIDTH: u16 = 21;
+const CHIMNEY_X_OFFSET: u16 = 15;
+const EAVE_ROW: u16 = 3;
+const DOOR_X_OFFSET: u16 = 11;
+
+/// A log cabin in a valley with snow-capped peaks behind it.
+pub struct CabinTheme {
+    decorations: Decorations,
+}
+
+impl CabinTheme {
+    pub fn new(season: Season) -> Self {
+        Self {
+            decorations: Decorations::new(season),
+        }
+    }
+
+    fn get_cabin(&self) -> Vec<&'static str> {
+        vec![
+            "               ||    ",
+            "     __________||_   ",
+            "    /              \\ ",
+            "   /________________\\",
+            "    |  []  |  |  [] |",
+            "    |      |  |     |",
+            "    |______|__|_____|",
+        ]
+    }
+
+    fn cabin_position(&self, width: u16, horizon_y: u16) -> (u16, u16) {
+        let x = (width / 2).saturating_sub(CABIN_WIDTH / 2);
+        let y = horizon_y.saturating_sub(self.get_cabin().len() as u16);
+        (x, y)
+    }
+
+    /// Height of the mountain range above the horizon at column `x`.
+    fn ridge_height(&self, x: u16, width: u16, horizon_y: u16) -> u16 {
+        let max_height = horizon_y.saturating_sub(3) as f32;
+        let peaks = [(0.15, 0.7), (0.4, 1.0), (0.7, 0.8), (0.95, 0.6)];
+
+        peaks
+            .iter()
+            .map(|&(position, scale)| {
+                let peak_x = width as f32 * position;
+                let distance = (x as f32 - peak_x).abs();
+                // Cells are twice as tall as wide, so the slope rises one row every two columns
+                (max_height * scale - distance / 2.0).max(0.0)
+            })
+            .fold(0.0, f32::max) as u16
+    }
+}
+
+impl SceneTheme for CabinTheme {
+    fn anchors(&self, width: u16, height: u16) -> SceneAnchors {
+        let horizon_y = self.horizon_y(height);
+        let (x, y) = self.cabin_position(width, horizon_y);
+
+        SceneAnchors {
+            horizon_y,
+            emitters: vec![(x + CHIMNEY_X_OFFSET, y)],
+            lights: Some((x + 4, y + EAVE_ROW, CABIN_WIDTH - 4)),
+            porch: Some((x + CABIN_WIDTH + 1, horizon_y.saturating_sub(2))),
+        }
+    }
+
+    fn add_collision(&self, mask: &mut CollisionMask, width: u16, height: u16) {
+        let horizon_y = self.horizon_y(height);
+        let (x, y) = self.cabin_position(width, horizon_y);
+        mask.add_art(x, y, &self.get_cabin());
+
+        let (pine, _) = self.decorations.get_pine_tree(true);
+        let pine_y = horizon_y.saturating_sub(pine.len() as u16);
+        mask.add_art(x.saturating_sub(12), pine_y, &pine);
+        mask.add_art(x + CABIN_WIDTH + 6, pine_y, &pine);
+    }
+
+    fn render(&self, renderer: &mut TerminalRenderer, ctx: &ThemeContext) -> io::Result<()> {
+        let horizon_y = self.horizon_y(ctx.height);
+
+        let (rock_color, snow_color) = if ctx.is_day {
+            (Color::DarkGrey, Color::White)
+        } else {
+            (
+                Color::Rgb {
+                    r: 50,
+                    g: 50,
+                    b: 70,
+                },
+                Color::Grey,
+            )
+        };
+
+        // Mountain outline, with snow on the upper slopes
+        let max_height = horizon_y.saturating_sub(3);
+        for x in 0..ctx.width {
+            let h = self.ridge_height(x, ctx.width, horizon_y);
+            if h == 0 {
+                continue;
+            }
+
+            let left = self.ridge_height(x.saturating_sub(1), ctx.width, horizon_y);
+            let right = self.ridge_height(x + 1, ctx.width, horizon_y);
+            let ch = if left < h && right < h {
+                '^'
+            } else if right > h || left < h {
+                '/'
+            } else {
+                '\\'
+            };
+
+            let color = if h * 3 > max_height * 2 {
+                snow_color
+            } else {
+                rock_color
+            };
+            renderer.render_char(x, horizon_y.saturating_sub(h), ch, color)?;
+        }
+
+        let (x, y) = self.cabin_position(ctx.width, horizon_y);
+        let (pine, pine_color) = self.decorations.get_pine_tree(ctx.is_day);
+        let pine_y = horizon_y.saturating_sub(pine.len() as u16);
+        render_art(renderer, x.saturating_sub(12), pine_y, &pine, pine_color)?;
+        render_art(renderer, x + CABIN_WIDTH + 6, pine_y, &pine, pine_color)?;
+
+        let log_color = if ctx.is_day {
+            Color::Rgb {
+                r: 139,
+                g: 90,
+                b: 43,
+            }
+        } else {
+            Color::Rgb {
+                r: 80,
+                g: 50,
+                b: 30,
+            }
+        };
+        let window_color = if ctx.is_day {
+            Color::Cyan
+        } else {
+            Color::Yellow
+        };
+
+        for (i, line) in self.get_cabin().iter().enumerate() {
+            for (j, ch) in line.chars().enumerate() {
+                if ch == ' ' {
+                    continue;
+                }
+                let color = match ch {
+                    '[' | ']' => window_color,
+                    _ if i <= 3 => Color::DarkRed,
+                    _ => log_color,
+                };
+                renderer.render_char(x + j as u16, y + i as u16, ch, color)?;
+            }
+        }
+
+        // Door
+        renderer.render_char(x + DOOR_X_OFFSET + 2, y + 5, 'o', Color::DarkYellow)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cabin_art_is_rectangular() {
+        let theme = CabinTheme::new(Season::Summer);
+        assert!(
+            theme
+                .get_cabin()
+                .iter()
+                .all(|line| line.len() == CABIN_WIDTH as usize)
+        );
+    }
+
+    #[test]
+    fn test_ridge_stays_below_sky_top() {
+        let theme = CabinTheme::new(Season::Winter);
+        for x in 0..100 {
+            assert!(theme.ridge_height(x, 100, 20) <= 17);
+        }
+    }
+}
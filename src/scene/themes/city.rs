@@ -0,0 +1,177 @@
+use super::{SceneAnchors, SceneTheme, ThemeContext};
+use crate::render::TerminalRenderer;
+use crate::scene::collision::CollisionMask;
+use crate::scene::ground::GroundStyle;
+use crossterm::style::Color;
+use std::io;
+
+// Simple seeded RNG so the skyline is stable between frames
+fn pseudo_rand(x: u32, y: u32) -> u32 {
+    (x ^ 0x5DEECE6)
+        .wrapping_mul(y ^ 0xB)
+        .wrapping_add(x.wrapping_mul(31))
+        % 100
+}
+
+struct Building {
+    x: u16,
+    width: u16,
+    height: u16,
+    has_vent: bool,
+}
+
+/// A skyline of office towers whose windows light up at night.
+pub struct CityTheme;
+
+impl CityTheme {
+    fn buildings(&self, width: u16, horizon_y: u16) -> Vec<Building> {
+        let max_height = horizon_y.saturating_sub(4).max(4);
+        let mut buildings = Vec::new();
+        let mut x = 1;
+        let mut i = 0;
+
+        while x + 6 < width {
+            let seed = pseudo_rand(i + 3, 17);
+            let building_width = 8 + (seed % 9) as u16;
+            let height = (5 + (pseudo_rand(i + 11, 5) as u16 % max_height)).min(max_height);
+            buildings.push(Building {
+                x,
+                width: building_width.min(width - x - 1),
+                height,
+                has_vent: seed % 3 == 0,
+            });
+            x += building_width + 1 + (seed % 3) as u16;
+            i += 1;
+        }
+
+        buildings
+    }
+
+    fn is_lit(building: usize, window: usize, frame: u64) -> bool {
+        // Lights switch on and off every few minutes
+        let epoch = (frame / 1800) as u32;
+        pseudo_rand(building as u32 * 7 + window as u32, epoch + 1) < 45
+    }
+}
+
+impl SceneTheme for CityTheme {
+    fn ground_style(&self) -> GroundStyle {
+        GroundStyle::Pavement
+    }
+
+    fn anchors(&self, width: u16, height: u16) -> SceneAnchors {
+        let horizon_y = self.horizon_y(height);
+        let buildings = self.buildings(width, horizon_y);
+
+        let emitters = buildings
+            .iter()
+            .filter(|b| b.has_vent)
+            .map(|b| (b.x + b.width / 2, horizon_y.saturating_sub(b.height + 1)))
+            .collect();
+
+        // Lights run along the roof of the building closest to the centre
+        let centre = buildings
+            .iter()
+            .min_by_key(|b| (b.x + b.width / 2).abs_diff(width / 2));
+
+        SceneAnchors {
+            horizon_y,
+            emitters,
+            lights: centre.map(|b| (b.x, horizon_y.saturating_sub(b.height), b.width)),
+            porch: centre.map(|b| (b.x + b.width / 2 + 2, horizon_y.saturating_sub(2))),
+        }
+    }
+
+    fn add_collision(&self, mask: &mut CollisionMask, width: u16, height: u16) {
+        let horizon_y = self.horizon_y(height);
+        for building in self.buildings(width, horizon_y) {
+            for y in horizon_y.saturating_sub(building.height)..horizon_y {
+                for x in building.x..building.x + building.width {
+                    mask.set(x, y);
+                }
+            }
+        }
+    }
+
+    fn render(&self, renderer: &mut TerminalRenderer, ctx: &ThemeContext) -> io::Result<()> {
+        let horizon_y = self.horizon_y(ctx.height);
+
+        let wall_color = if ctx.is_day {
+            Color::Grey
+        } else {
+            Color::DarkGrey
+        };
+        let dark_window = if ctx.is_day {
+            Color::DarkCyan
+        } else {
+            Color::Rgb {
+                r: 30,
+                g: 30,
+                b: 40,
+            }
+        };
+
+        for (b, building) in self.buildings(ctx.width, horizon_y).iter().enumerate() {
+            let top = horizon_y.saturating_sub(building.height);
+            let right = building.x + building.width - 1;
+
+            for x in building.x..=right {
+                renderer.render_char(x, top, '_', wall_color)?;
+            }
+
+            let mut window = 0;
+            for y in top + 1..horizon_y {
+                renderer.render_char(building.x, y, '|', wall_color)?;
+                renderer.render_char(right, y, '|', wall_color)?;
+
+                // Windows on every other floor, leaving the ground floor for the door
+                if (y - top) % 2 == 1 && y + 2 < horizon_y {
+                    let mut x = building.x + 2;
+                    while x + 1 < right {
+                        let color = if !ctx.is_day && Self::is_lit(b, window, ctx.frame) {
+                            Color::Yellow
+                        } else {
+                            dark_window
+                        };
+                        renderer.render_line_colored(x, y, "[]", color)?;
+                        window += 1;
+                        x += 3;
+                    }
+                }
+            }
+
+            let door_x = building.x + building.width / 2;
+            if building.width >= 6 {
+                renderer.render_line_colored(
+                    door_x - 1,
+                    horizon_y.saturating_sub(1),
+                    "|_|",
+                    wall_color,
+                )?;
+            }
+
+            if building.has_vent {
+                renderer.render_char(door_x, top.saturating_sub(1), 'n', wall_color)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buildings_fill_width_without_overlap() {
+        let buildings = CityTheme.buildings(120, 30);
+
+        assert!(buildings.len() > 4);
+        for pair in buildings.windows(2) {
+            assert!(pair[0].x + pair[0].width <= pair[1].x);
+        }
+        assert!(buildings.iter().all(|b| b.x + b.width < 120));
+        assert!(buildings.iter().all(|b| b.height <= 26));
+    }
+}
@@ -0,0 +1,153 @@
+use super::{SceneAnchors, SceneTheme, ThemeContext};
+use crate::render::TerminalRenderer;
+use crate::scene::collision::CollisionMask;
+use crate::scene::decorations::{DecorationRenderConfig, Decorations};
+use crate::scene::house::House;
+use crate::scene::layout::LayoutMode;
+use crate::scene::season::Season;
+use crate::weather::beaufort_scale;
+use std::io;
+
+/// The original scene: a house with a garden, a fence and a weathervane.
+pub struct CottageTheme {
+    house: House,
+    decorations: Decorations,
+}
+
+impl CottageTheme {
+    pub fn new(season: Season) -> Self {
+        Self {
+            house: House,
+            decorations: Decorations::new(season),
+        }
+    }
+
+    fn house_position(&self, width: u16, horizon_y: u16) -> (u16, u16) {
+        let house_x = (width / 2).saturating_sub(self.house.width() / 2);
+        let house_y = horizon_y.saturating_sub(self.house.height());
+        (house_x, house_y)
+    }
+
+    fn small_house_position(&self, width: u16, horizon_y: u16) -> (u16, u16) {
+        let house_x = (width / 2).saturating_sub(House::SMALL_WIDTH / 2);
+        let house_y = horizon_y.saturating_sub(House::SMALL_HEIGHT);
+        (house_x, house_y)
+    }
+
+    fn decoration_config(&self, width: u16, height: u16, is_day: bool) -> DecorationRenderConfig {
+        let horizon_y = self.horizon_y(height);
+        let (house_x, _) = self.house_position(width, horizon_y);
+        DecorationRenderConfig {
+            horizon_y,
+            house_x,
+            house_width: self.house.width(),
+            width,
+            is_day,
+        }
+    }
+}
+
+impl SceneTheme for CottageTheme {
+    fn anchors(&self, width: u16, height: u16) -> SceneAnchors {
+        let horizon_y = self.horizon_y(height);
+
+        if LayoutMode::for_size(width, height) == LayoutMode::Compact {
+            let (house_x, house_y) = self.small_house_position(width, horizon_y);
+            return SceneAnchors {
+                horizon_y,
+                emitters: vec![(house_x + House::SMALL_CHIMNEY_X_OFFSET, house_y)],
+                lights: Some((house_x, house_y + House::SMALL_EAVE_ROW, House::SMALL_WIDTH)),
+                porch: Some((
+                    house_x + House::SMALL_WIDTH + 1,
+                    horizon_y.saturating_sub(2),
+                )),
+            };
+        }
+
+        let (house_x, house_y) = self.house_position(width, horizon_y);
+
+        SceneAnchors {
+            horizon_y,
+            emitters: vec![(house_x + House::CHIMNEY_X_OFFSET, house_y)],
+            lights: Some((
+                house_x + House::EAVE_X_OFFSET,
+                house_y + House::EAVE_ROW,
+                House::EAVE_WIDTH,
+            )),
+            // On the porch, just right of the door
+            porch: Some((
+                house_x + House::DOOR_X_OFFSET + 6,
+                house_y + self.house.height() - 2,
+            )),
+        }
+    }
+
+    fn add_collision(&self, mask: &mut CollisionMask, width: u16, height: u16) {
+        let horizon_y = self.horizon_y(height);
+
+        if LayoutMode::for_size(width, height) == LayoutMode::Compact {
+            let (house_x, house_y) = self.small_house_position(width, horizon_y);
+            mask.add_art(house_x, house_y, &House::get_small_ascii());
+            return;
+        }
+
+        let (house_x, house_y) = self.house_position(width, horizon_y);
+
+        for (i, line) in self.house.get_ascii().iter().enumerate() {
+            for (j, ch) in line.chars().enumerate() {
+                if House::is_solid(i, ch) {
+                    mask.set(house_x + j as u16, house_y + i as u16);
+                }
+            }
+        }
+
+        let config = self.decoration_config(width, height, true);
+        for placement in self.decorations.placements(&config) {
+            mask.add_art(placement.x, placement.y, &placement.lines);
+        }
+    }
+
+    fn render(&self, renderer: &mut TerminalRenderer, ctx: &ThemeContext) -> io::Result<()> {
+        let horizon_y = self.horizon_y(ctx.height);
+
+        // Small terminals only get the house, without any decorations
+        if LayoutMode::for_size(ctx.width, ctx.height) == LayoutMode::Compact {
+            let (house_x, house_y) = self.small_house_position(ctx.width, horizon_y);
+            return self
+                .house
+                .render_small(renderer, house_x, house_y, ctx.is_day);
+        }
+
+        let (house_x, house_y) = self.house_position(ctx.width, horizon_y);
+
+        self.house.render(renderer, house_x, house_y, ctx.is_day)?;
+
+        let decoration_config = self.decoration_config(ctx.width, ctx.height, ctx.is_day);
+        self.decorations.render(renderer, &decoration_config)?;
+
+        // Render wind instruments
+        self.decorations.render_weathervane(
+            renderer,
+            house_x + House::RIDGE_X_OFFSET,
+            house_y,
+            ctx.wind.direction,
+            ctx.is_day,
+        )?;
+
+        if let Some((pole_x, pole_y)) = self.decorations.windsock_anchor(&decoration_config) {
+            // Wind blowing from the east pushes the sock to the left
+            let blows_left = ctx.wind.direction.to_radians().sin() > 0.0;
+            self.decorations.render_windsock(
+                renderer,
+                pole_x,
+                pole_y,
+                beaufort_scale(ctx.wind.speed),
+                blows_left,
+                ctx.frame,
+                ctx.is_day,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,592 @@
+use super::{SceneAnchors, SceneTheme, ThemeContext};
+use crate::error::ConfigError;
+use crate::render::TerminalRenderer;
+use crate::scene::collision::CollisionMask;
+use crate::scene::ground::GroundStyle;
+use crossterm::style::Color;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use toml::Spanned;
+
+/// Largest offset a scene file may use. Far beyond any terminal, and small
+/// enough that adding it to a screen position cannot overflow.
+const MAX_OFFSET: u16 = 1000;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SceneFile {
+    #[serde(default)]
+    ground: Option<Spanned<String>>,
+    layers: Spanned<Vec<Spanned<LayerDef>>>,
+    #[serde(default)]
+    emitters: Vec<Spanned<AnchorDef>>,
+    #[serde(default)]
+    lights: Option<Spanned<LightsDef>>,
+    #[serde(default)]
+    porch: Option<Spanned<AnchorDef>>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LayerDef {
+    art: String,
+    #[serde(default)]
+    align: Align,
+    /// Columns from the alignment point, negative moves left
+    #[serde(default)]
+    x: i32,
+    /// Rows between the bottom of the art and the horizon
+    #[serde(default)]
+    y: u16,
+    #[serde(default)]
+    color: Option<Spanned<String>>,
+    #[serde(default)]
+    night_color: Option<Spanned<String>>,
+    #[serde(default)]
+    colors: HashMap<String, Spanned<String>>,
+    #[serde(default)]
+    night_colors: HashMap<String, Spanned<String>>,
+    /// Rows of the art that precipitation collides with, all rows when omitted
+    #[serde(default)]
+    collision_rows: Option<Spanned<Vec<usize>>>,
+}
+
+#[derive(Deserialize, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+enum Align {
+    Left,
+    #[default]
+    Center,
+    Right,
+}
+
+/// A point relative to the top-left corner of a layer.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AnchorDef {
+    #[serde(default)]
+    layer: usize,
+    x: u16,
+    y: u16,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LightsDef {
+    #[serde(default)]
+    layer: usize,
+    x: u16,
+    y: u16,
+    length: u16,
+}
+
+struct Layer {
+    lines: Vec<String>,
+    align: Align,
+    x: i32,
+    y: u16,
+    day_color: Color,
+    night_color: Color,
+    day_colors: HashMap<char, Color>,
+    night_colors: HashMap<char, Color>,
+    collision_rows: Option<Vec<usize>>,
+}
+
+impl Layer {
+    fn width(&self) -> u16 {
+        self.lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0) as u16
+    }
+
+    fn origin(&self, width: u16, horizon_y: u16) -> (u16, u16) {
+        let art_width = self.width() as i32;
+        let base = match self.align {
+            Align::Left => 0,
+            Align::Center => width as i32 / 2 - art_width / 2,
+            Align::Right => width as i32 - art_width,
+        };
+        let x = (base + self.x).max(0) as u16;
+        let y = horizon_y.saturating_sub(self.y.saturating_add(self.lines.len() as u16));
+        (x, y)
+    }
+
+    fn color_of(&self, ch: char, is_day: bool) -> Color {
+        if is_day {
+            self.day_colors.get(&ch).copied().unwrap_or(self.day_color)
+        } else {
+            self.night_colors
+                .get(&ch)
+                .or_else(|| self.day_colors.get(&ch))
+                .copied()
+                .unwrap_or(self.night_color)
+        }
+    }
+}
+
+struct Anchor {
+    layer: usize,
+    x: u16,
+    y: u16,
+}
+
+/// A scene drawn by the user, loaded from a TOML file in the scenes
+/// directory of the config folder.
+pub struct CustomTheme {
+    ground: GroundStyle,
+    layers: Vec<Layer>,
+    emitters: Vec<Anchor>,
+    lights: Option<(Anchor, u16)>,
+    porch: Option<Anchor>,
+}
+
+impl CustomTheme {
+    pub fn load_from_path(path: &Path) -> Result<Self, ConfigError> {
+        let content = fs::read_to_string(path).map_err(|e| ConfigError::ReadError {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+
+        Self::parse(&content).map_err(|(offset, message)| ConfigError::InvalidScene {
+            path: path.display().to_string(),
+            line: line_of(&content, offset),
+            message,
+        })
+    }
+
+    /// Parses a scene definition. Errors carry the byte offset they refer to.
+    fn parse(content: &str) -> Result<Self, (usize, String)> {
+        let file: SceneFile = toml::from_str(content).map_err(|e| {
+            let offset = e.span().map_or(0, |span| span.start);
+            (offset, e.message().to_string())
+        })?;
+
+        let ground = match &file.ground {
+            None => GroundStyle::Grass,
+            Some(value) => match value.get_ref().as_str() {
+                "grass" => GroundStyle::Grass,
+                "pavement" => GroundStyle::Pavement,
+                "sand" => GroundStyle::Sand,
+                "indoor" => GroundStyle::Indoor,
+                other => {
+                    return Err((
+                        value.span().start,
+                        format!(
+                            "unknown ground '{other}', expected grass, pavement, sand or indoor"
+                        ),
+                    ));
+                }
+            },
+        };
+
+        let layers_span = file.layers.span();
+        let layers = file
+            .layers
+            .into_inner()
+            .into_iter()
+            .map(Layer::from_def)
+            .collect::<Result<Vec<_>, _>>()?;
+        if layers.is_empty() {
+            return Err((layers_span.start, "a scene needs at least one layer".into()));
+        }
+
+        let anchor = |def: &Spanned<AnchorDef>| {
+            let inner = def.get_ref();
+            check_offsets(def.span().start, &[("x", inner.x), ("y", inner.y)])?;
+            if inner.layer >= layers.len() {
+                return Err((
+                    def.span().start,
+                    format!(
+                        "anchor refers to layer {} which does not exist",
+                        inner.layer
+                    ),
+                ));
+            }
+            Ok(Anchor {
+                layer: inner.layer,
+                x: inner.x,
+                y: inner.y,
+            })
+        };
+
+        let emitters = file.emitters.iter().map(anchor).collect::<Result<_, _>>()?;
+        let porch = file.porch.as_ref().map(anchor).transpose()?;
+        let lights = match &file.lights {
+            None => None,
+            Some(def) => {
+                let inner = def.get_ref();
+                check_offsets(
+                    def.span().start,
+                    &[("x", inner.x), ("y", inner.y), ("length", inner.length)],
+                )?;
+                if inner.layer >= layers.len() {
+                    return Err((
+                        def.span().start,
+                        format!("lights refer to layer {} which does not exist", inner.layer),
+                    ));
+                }
+                Some((
+                    Anchor {
+                        layer: inner.layer,
+                        x: inner.x,
+                        y: inner.y,
+                    },
+                    inner.length,
+                ))
+            }
+        };
+
+        Ok(Self {
+            ground,
+            layers,
+            emitters,
+            lights,
+            porch,
+        })
+    }
+
+    fn resolve(&self, anchor: &Anchor, width: u16, horizon_y: u16) -> (u16, u16) {
+        let (x, y) = self.layers[anchor.layer].origin(width, horizon_y);
+        (x.saturating_add(anchor.x), y.saturating_add(anchor.y))
+    }
+}
+
+impl Layer {
+    fn from_def(def: Spanned<LayerDef>) -> Result<Self, (usize, String)> {
+        let offset = def.span().start;
+        let def = def.into_inner();
+        check_offsets(offset, &[("y", def.y)])?;
+        if def.x.unsigned_abs() > u32::from(MAX_OFFSET) {
+            return Err((
+                offset,
+                format!(
+                    "x {} is out of range (-{MAX_OFFSET} to {MAX_OFFSET})",
+                    def.x
+                ),
+            ));
+        }
+
+        let lines: Vec<String> = def.art.lines().map(|l| l.trim_end().to_string()).collect();
+
+        let day_color = match &def.color {
+            Some(value) => parse_spanned_color(value)?,
+            None => Color::White,
+        };
+        let night_color = match &def.night_color {
+            Some(value) => parse_spanned_color(value)?,
+            None => day_color,
+        };
+
+        let color_map = |map: &HashMap<String, Spanned<String>>| {
+            map.iter()
+                .map(|(key, value)| {
+                    let mut chars = key.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(ch), None) => Ok((ch, parse_spanned_color(value)?)),
+                        _ => Err((
+                            value.span().start,
+                            format!("color map key '{key}' must be a single character"),
+                        )),
+                    }
+                })
+                .collect::<Result<HashMap<_, _>, _>>()
+        };
+
+        let collision_rows = match def.collision_rows {
+            None => None,
+            Some(rows) => {
+                if let Some(row) = rows.get_ref().iter().find(|&&row| row >= lines.len()) {
+                    return Err((
+                        rows.span().start,
+                        format!(
+                            "collision row {row} is outside the art ({} rows)",
+                            lines.len()
+                        ),
+                    ));
+                }
+                Some(rows.into_inner())
+            }
+        };
+
+        Ok(Self {
+            day_colors: color_map(&def.colors)?,
+            night_colors: color_map(&def.night_colors)?,
+            lines,
+            align: def.align,
+            x: def.x,
+            y: def.y,
+            day_color,
+            night_color,
+            collision_rows,
+        })
+    }
+}
+
+impl SceneTheme for CustomTheme {
+    fn ground_style(&self) -> GroundStyle {
+        self.ground
+    }
+
+    fn anchors(&self, width: u16, height: u16) -> SceneAnchors {
+        let horizon_y = self.horizon_y(height);
+
+        SceneAnchors {
+            horizon_y,
+            emitters: self
+                .emitters
+                .iter()
+                .map(|anchor| self.resolve(anchor, width, horizon_y))
+                // Smoke from off-screen would never be seen
+                .filter(|&(x, y)| x < width && y < height)
+                .collect(),
+            lights: self.lights.as_ref().map(|(anchor, length)| {
+                let (x, y) = self.resolve(anchor, width, horizon_y);
+                (x, y, *length)
+            }),
+            porch: self
+                .porch
+                .as_ref()
+                .map(|anchor| self.resolve(anchor, width, horizon_y)),
+        }
+    }
+
+    fn add_collision(&self, mask: &mut CollisionMask, width: u16, height: u16) {
+        let horizon_y = self.horizon_y(height);
+
+        for layer in &self.layers {
+            let (x, y) = layer.origin(width, horizon_y);
+            for (i, line) in layer.lines.iter().enumerate() {
+                let solid = layer
+                    .collision_rows
+                    .as_ref()
+                    .is_none_or(|rows| rows.contains(&i));
+                if solid {
+                    mask.add_art(x, y.saturating_add(i as u16), &[line.as_str()]);
+                }
+            }
+        }
+    }
+
+    fn render(&self, renderer: &mut TerminalRenderer, ctx: &ThemeContext) -> std::io::Result<()> {
+        let horizon_y = self.horizon_y(ctx.height);
+
+        for layer in &self.layers {
+            let (x, y) = layer.origin(ctx.width, horizon_y);
+            for (i, line) in layer.lines.iter().enumerate() {
+                for (j, ch) in line.chars().enumerate() {
+                    if ch != ' ' {
+                        renderer.render_char(
+                            x.saturating_add(j as u16),
+                            y.saturating_add(i as u16),
+                            ch,
+                            layer.color_of(ch, ctx.is_day),
+                        )?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Rejects offsets above `MAX_OFFSET`, reporting them at `offset`.
+fn check_offsets(offset: usize, values: &[(&str, u16)]) -> Result<(), (usize, String)> {
+    match values.iter().find(|(_, value)| *value > MAX_OFFSET) {
+        Some((name, value)) => Err((
+            offset,
+            format!("{name} {value} is out of range (0 to {MAX_OFFSET})"),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn parse_spanned_color(value: &Spanned<String>) -> Result<Color, (usize, String)> {
+    parse_color(value.get_ref()).ok_or_else(|| {
+        (
+            value.span().start,
+            format!("unknown color '{}'", value.get_ref()),
+        )
+    })
+}
+
+/// Accepts the terminal color names (`red`, `dark_blue`, ...) and `#rrggbb`.
+fn parse_color(value: &str) -> Option<Color> {
+    if let Some(hex) = value.strip_prefix('#') {
+        // Checked byte by byte so the slicing below stays on char boundaries
+        if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        return Some(Color::Rgb {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+        });
+    }
+
+    let color = match value.to_lowercase().replace('-', "_").as_str() {
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "white" => Color::White,
+        "grey" | "gray" => Color::Grey,
+        "dark_grey" | "dark_gray" => Color::DarkGrey,
+        "dark_red" => Color::DarkRed,
+        "dark_green" => Color::DarkGreen,
+        "dark_yellow" => Color::DarkYellow,
+        "dark_blue" => Color::DarkBlue,
+        "dark_magenta" => Color::DarkMagenta,
+        "dark_cyan" => Color::DarkCyan,
+        _ => return None,
+    };
+    Some(color)
+}
+
+fn line_of(content: &str, offset: usize) -> usize {
+    content[..offset.min(content.len())].matches('\n').count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOWER: &str = r##"
+ground = "pavement"
+
+[[layers]]
+color = "grey"
+night_color = "dark_grey"
+colors = { "#" = "#336699" }
+night_colors = { "#" = "yellow" }
+collision_rows = [0]
+art = '''
+ __||__
+|  ##  |
+|__##__|
+'''
+
+[[emitters]]
+x = 3
+y = 0
+
+[lights]
+x = 0
+y = 1
+length = 8
+"##;
+
+    #[test]
+    fn test_parse_scene() {
+        let theme = CustomTheme::parse(TOWER).unwrap();
+        assert_eq!(theme.ground, GroundStyle::Pavement);
+        assert_eq!(theme.layers.len(), 1);
+
+        let layer = &theme.layers[0];
+        assert_eq!(layer.width(), 8);
+        assert_eq!(
+            layer.color_of('#', true),
+            Color::Rgb {
+                r: 0x33,
+                g: 0x66,
+                b: 0x99
+            }
+        );
+        assert_eq!(layer.color_of('#', false), Color::Yellow);
+        assert_eq!(layer.color_of('|', false), Color::DarkGrey);
+    }
+
+    #[test]
+    fn test_anchors_follow_layer_position() {
+        let theme = CustomTheme::parse(TOWER).unwrap();
+        let anchors = theme.anchors(100, 30);
+
+        // Centred 8-wide art sitting on the horizon at row 23
+        assert_eq!(anchors.horizon_y, 23);
+        assert_eq!(anchors.emitters, vec![(46 + 3, 20)]);
+        assert_eq!(anchors.lights, Some((46, 21, 8)));
+    }
+
+    #[test]
+    fn test_collision_rows_limit_solid_cells() {
+        let theme = CustomTheme::parse(TOWER).unwrap();
+        let mut mask = CollisionMask::new(100, 30);
+        theme.add_collision(&mut mask, 100, 30);
+
+        assert!(mask.is_solid(48.0, 20.0));
+        assert!(!mask.is_solid(46.0, 21.0));
+    }
+
+    #[test]
+    fn test_errors_report_line_numbers() {
+        let content = "[[layers]]\nart = \"x\"\ncolor = \"mauve\"\n";
+        let (offset, message) = CustomTheme::parse(content).err().unwrap();
+        assert_eq!(line_of(content, offset), 3);
+        assert!(message.contains("mauve"));
+
+        let content = "[[layers]]\nart = \"x\"\n\n[[emitters]]\nlayer = 2\nx = 0\ny = 0\n";
+        let (offset, _) = CustomTheme::parse(content).err().unwrap();
+        // Anchor errors point at the table header
+        assert_eq!(line_of(content, offset), 4);
+
+        let content = "[[layers]]\nart = \"x\"\nsize = 3\n";
+        let (offset, message) = CustomTheme::parse(content).err().unwrap();
+        assert_eq!(line_of(content, offset), 3);
+        assert!(message.contains("size"));
+    }
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("dark-blue"), Some(Color::DarkBlue));
+        assert_eq!(
+            parse_color("#ff0000"),
+            Some(Color::Rgb { r: 255, g: 0, b: 0 })
+        );
+        assert_eq!(parse_color("#ff00"), None);
+        assert_eq!(parse_color("mauve"), None);
+        assert_eq!(parse_color("#aé123"), None);
+        assert_eq!(parse_color("#12345g"), None);
+    }
+
+    #[test]
+    fn test_out_of_range_offsets_are_errors() {
+        let content = "[[layers]]\nart = \"x\"\ny = 65535\n";
+        let (offset, message) = CustomTheme::parse(content).err().unwrap();
+        assert_eq!(line_of(content, offset), 1);
+        assert!(message.contains("65535"));
+
+        let content = "[[layers]]\nart = \"x\"\nx = -70000\n";
+        let (_, message) = CustomTheme::parse(content).err().unwrap();
+        assert!(message.contains("-70000"));
+
+        let content = "[[layers]]\nart = \"x\"\n\n[[emitters]]\nx = 65535\ny = 0\n";
+        let (offset, message) = CustomTheme::parse(content).err().unwrap();
+        assert_eq!(line_of(content, offset), 4);
+        assert!(message.contains("65535"));
+
+        let content = "[[layers]]\nart = \"x\"\n\n[porch]\nx = 0\ny = 65535\n";
+        assert!(CustomTheme::parse(content).is_err());
+
+        // The largest accepted offsets still resolve on a tiny terminal, but
+        // an emitter that lands off-screen is dropped
+        let content =
+            "[[layers]]\nart = \"x\"\ny = 1000\nx = 1000\n\n[[emitters]]\nx = 1000\ny = 1000\n";
+        let theme = CustomTheme::parse(content).unwrap();
+        assert!(theme.anchors(10, 3).emitters.is_empty());
+    }
+
+    #[test]
+    fn test_non_ascii_color_is_an_error() {
+        let content = "[[layers]]\nart = \"x\"\ncolor = \"#aé123\"\n";
+        let (offset, message) = CustomTheme::parse(content).err().unwrap();
+        assert_eq!(line_of(content, offset), 3);
+        assert!(message.contains("#aé123"));
+    }
+}
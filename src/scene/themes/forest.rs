@@ -0,0 +1,137 @@
+use super::{SceneAnchors, SceneTheme, ThemeContext, render_art, render_campfire};
+use crate::render::TerminalRenderer;
+use crate::scene::collision::CollisionMask;
+use crate::scene::decorations::{Decorations, Placement};
+use crate::scene::season::Season;
+use crossterm::style::Color;
+use std::io;
+
+const TENT_WIDTH: u16 = 11;
+const TREE_SPACING: u16 = 14;
+
+/// A clearing in the woods with a tent and a campfire.
+pub struct ForestTheme {
+    decorations: Decorations,
+}
+
+impl ForestTheme {
+    pub fn new(season: Season) -> Self {
+        Self {
+            decorations: Decorations::new(season),
+        }
+    }
+
+    fn get_tent(&self) -> Vec<&'static str> {
+        vec![
+            "     /\\    ",
+            "    /  \\   ",
+            "   / /\\ \\  ",
+            "  / /  \\ \\ ",
+            " /_/____\\_\\",
+        ]
+    }
+
+    fn tent_position(&self, width: u16, horizon_y: u16) -> (u16, u16) {
+        let x = (width / 2).saturating_sub(TENT_WIDTH / 2);
+        let y = horizon_y.saturating_sub(self.get_tent().len() as u16);
+        (x, y)
+    }
+
+    /// Tree positions across the width, leaving the clearing in the middle
+    /// free. Pines alternate with the seasonal broadleaf trees.
+    fn trees(&self, width: u16, horizon_y: u16, is_day: bool) -> Vec<Placement> {
+        let clearing_start = (width / 2).saturating_sub(TENT_WIDTH + 8);
+        let clearing_end = width / 2 + TENT_WIDTH + 14;
+
+        let mut trees = Vec::new();
+        let mut x = 1;
+        let mut i = 0;
+        while x + 16 < width {
+            let (lines, color) = if i % 2 == 0 {
+                self.decorations.get_pine_tree(is_day)
+            } else {
+                self.decorations.get_tree(is_day)
+            };
+            let tree_width = lines[0].len() as u16;
+
+            if x + tree_width < clearing_start || x > clearing_end {
+                let y = horizon_y.saturating_sub(lines.len() as u16);
+                trees.push(Placement { x, y, lines, color });
+            }
+            x += TREE_SPACING;
+            i += 1;
+        }
+        trees
+    }
+}
+
+impl SceneTheme for ForestTheme {
+    fn anchors(&self, width: u16, height: u16) -> SceneAnchors {
+        let horizon_y = self.horizon_y(height);
+        let (tent_x, tent_y) = self.tent_position(width, horizon_y);
+        let fire_x = tent_x + TENT_WIDTH + 4;
+
+        SceneAnchors {
+            horizon_y,
+            emitters: vec![(fire_x + 2, horizon_y.saturating_sub(3))],
+            // Along the bottom of the tent
+            lights: Some((tent_x + 1, tent_y + 4, TENT_WIDTH - 1)),
+            porch: Some((tent_x.saturating_sub(6), horizon_y.saturating_sub(2))),
+        }
+    }
+
+    fn add_collision(&self, mask: &mut CollisionMask, width: u16, height: u16) {
+        let horizon_y = self.horizon_y(height);
+        let (tent_x, tent_y) = self.tent_position(width, horizon_y);
+        mask.add_art(tent_x, tent_y, &self.get_tent());
+
+        for tree in self.trees(width, horizon_y, true) {
+            mask.add_art(tree.x, tree.y, &tree.lines);
+        }
+    }
+
+    fn render(&self, renderer: &mut TerminalRenderer, ctx: &ThemeContext) -> io::Result<()> {
+        let horizon_y = self.horizon_y(ctx.height);
+
+        for tree in self.trees(ctx.width, horizon_y, ctx.is_day) {
+            render_art(renderer, tree.x, tree.y, &tree.lines, tree.color)?;
+        }
+
+        let (tent_x, tent_y) = self.tent_position(ctx.width, horizon_y);
+        let tent_color = if ctx.is_day {
+            Color::DarkYellow
+        } else {
+            Color::Rgb {
+                r: 90,
+                g: 80,
+                b: 40,
+            }
+        };
+        render_art(renderer, tent_x, tent_y, &self.get_tent(), tent_color)?;
+
+        render_campfire(
+            renderer,
+            tent_x + TENT_WIDTH + 4,
+            horizon_y.saturating_sub(1),
+            ctx.frame,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trees_leave_clearing_for_tent() {
+        let theme = ForestTheme::new(Season::Autumn);
+        let (tent_x, _) = theme.tent_position(160, 40);
+        let trees = theme.trees(160, 40, true);
+
+        assert!(!trees.is_empty());
+        for tree in trees {
+            let right = tree.x + tree.lines[0].len() as u16;
+            assert!(right <= tent_x || tree.x >= tent_x + TENT_WIDTH + 10);
+        }
+    }
+}
@@ -0,0 +1,245 @@
+pub mod beach;
+pub mod cabin;
+pub mod city;
+pub mod cottage;
+pub mod custom;
+pub mod forest;
+pub mod office;
+
+use crate::config::Config;
+use crate::error::ConfigError;
+use crate::render::TerminalRenderer;
+use crate::scene::collision::CollisionMask;
+use crate::scene::ground::GroundStyle;
+use crate::scene::layout;
+use crate::scene::season::Season;
+use crate::weather::WindConditions;
+use crossterm::style::Color;
+use serde::Deserialize;
+use std::io;
+
+/// Points where weather effects meet a theme's scenery.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SceneAnchors {
+    /// First row of the ground. Rain lands here and fireflies hover above it.
+    pub horizon_y: u16,
+    /// Chimney tops and other points that give off smoke or steam
+    pub emitters: Vec<(u16, u16)>,
+    /// Start and length of the row that carries the holiday string lights
+    pub lights: Option<(u16, u16, u16)>,
+    /// Spot on the ground beside the door for the Halloween pumpkin
+    pub porch: Option<(u16, u16)>,
+}
+
+/// Per-frame state a theme needs to draw itself.
+pub struct ThemeContext<'a> {
+    pub width: u16,
+    pub height: u16,
+    pub is_day: bool,
+    pub frame: u64,
+    pub wind: &'a WindConditions,
+}
+
+/// A set of static scenery drawn between the sky and the foreground
+/// weather. The ground strip below the horizon is owned by `WorldScene`.
+pub trait SceneTheme {
+    fn ground_style(&self) -> GroundStyle {
+        GroundStyle::Grass
+    }
+
+    fn horizon_y(&self, height: u16) -> u16 {
+        height.saturating_sub(layout::ground_height(height))
+    }
+
+    fn anchors(&self, width: u16, height: u16) -> SceneAnchors;
+
+    /// Marks the cells covered by the theme's art, above the ground.
+    fn add_collision(&self, mask: &mut CollisionMask, width: u16, height: u16);
+
+    fn render(&self, renderer: &mut TerminalRenderer, ctx: &ThemeContext) -> io::Result<()>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+#[serde(try_from = "String")]
+pub enum SceneKind {
+    #[default]
+    House,
+    City,
+    Cabin,
+    Beach,
+    Forest,
+    Office,
+    /// A scene file `<name>.toml` in the `scenes` directory of the config folder
+    Custom(String),
+}
+
+impl SceneKind {
+    pub fn build(&self, season: Season) -> Result<Box<dyn SceneTheme>, ConfigError> {
+        let theme: Box<dyn SceneTheme> = match self {
+            SceneKind::House => Box::new(cottage::CottageTheme::new(season)),
+            SceneKind::City => Box::new(city::CityTheme),
+            SceneKind::Cabin => Box::new(cabin::CabinTheme::new(season)),
+            SceneKind::Beach => Box::new(beach::BeachTheme),
+            SceneKind::Forest => Box::new(forest::ForestTheme::new(season)),
+            SceneKind::Office => Box::new(office::OfficeTheme),
+            SceneKind::Custom(name) => {
+                let path = Config::config_dir()?
+                    .join("scenes")
+                    .join(format!("{name}.toml"));
+                Box::new(custom::CustomTheme::load_from_path(&path)?)
+            }
+        };
+        Ok(theme)
+    }
+}
+
+impl std::str::FromStr for SceneKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "house" => Ok(Self::House),
+            "city" => Ok(Self::City),
+            "cabin" | "mountain" => Ok(Self::Cabin),
+            "beach" => Ok(Self::Beach),
+            "forest" => Ok(Self::Forest),
+            "office" => Ok(Self::Office),
+            _ if !s.is_empty()
+                && s.chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') =>
+            {
+                Ok(Self::Custom(s.to_string()))
+            }
+            _ => Err(format!(
+                "Invalid scene: '{}'. Use house, city, cabin, beach, forest, office or the name of a file in the scenes directory",
+                s
+            )),
+        }
+    }
+}
+
+impl TryFrom<String> for SceneKind {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Draws every non-space character of `lines` with its top-left corner at `(x, y)`.
+pub fn render_art(
+    renderer: &mut TerminalRenderer,
+    x: u16,
+    y: u16,
+    lines: &[&str],
+    color: Color,
+) -> io::Result<()> {
+    for (i, line) in lines.iter().enumerate() {
+        for (j, ch) in line.chars().enumerate() {
+            if ch != ' ' {
+                renderer.render_char(x + j as u16, y + i as u16, ch, color)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A small fire of flickering flames on a bed of logs. `(x, y)` is the
+/// left end of the logs; the flames are drawn on the row above.
+pub fn render_campfire(
+    renderer: &mut TerminalRenderer,
+    x: u16,
+    y: u16,
+    frame: u64,
+) -> io::Result<()> {
+    const FLAMES: [&str; 3] = [" (^) ", " )^( ", " (^( "];
+    let flames = FLAMES[(frame / 3) as usize % FLAMES.len()];
+
+    for (j, ch) in flames.chars().enumerate() {
+        if ch != ' ' {
+            let color = if ch == '^' { Color::Yellow } else { Color::Red };
+            renderer.render_char(x + j as u16, y.saturating_sub(1), ch, color)?;
+        }
+    }
+
+    let logs_color = Color::Rgb {
+        r: 101,
+        g: 67,
+        b: 33,
+    };
+    renderer.render_line_colored(x, y, "/===\\", logs_color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scene_kind_from_str() {
+        assert_eq!("city".parse::<SceneKind>(), Ok(SceneKind::City));
+        assert_eq!("Beach".parse::<SceneKind>(), Ok(SceneKind::Beach));
+        assert_eq!("mountain".parse::<SceneKind>(), Ok(SceneKind::Cabin));
+        assert_eq!(
+            "castle".parse::<SceneKind>(),
+            Ok(SceneKind::Custom("castle".to_string()))
+        );
+        assert!("../castle".parse::<SceneKind>().is_err());
+    }
+
+    #[test]
+    fn test_every_theme_has_anchors_on_screen() {
+        let kinds = [
+            SceneKind::House,
+            SceneKind::City,
+            SceneKind::Cabin,
+            SceneKind::Beach,
+            SceneKind::Forest,
+            SceneKind::Office,
+        ];
+
+        for kind in kinds {
+            let theme = kind.build(Season::Summer).unwrap();
+            let anchors = theme.anchors(160, 50);
+
+            assert_eq!(anchors.horizon_y, theme.horizon_y(50), "{kind:?}");
+            for &(x, y) in &anchors.emitters {
+                assert!(x < 160 && y < 50, "{kind:?} emitter");
+            }
+        }
+    }
+
+    #[test]
+    fn test_themes_fit_minimum_terminal() {
+        let kinds = [
+            SceneKind::House,
+            SceneKind::City,
+            SceneKind::Cabin,
+            SceneKind::Beach,
+            SceneKind::Forest,
+            SceneKind::Office,
+        ];
+
+        for kind in kinds {
+            let theme = kind.build(Season::Winter).unwrap();
+            for (width, height) in [(70, 20), (40, 12)] {
+                let mut mask = CollisionMask::new(width, height);
+                theme.add_collision(&mut mask, width, height);
+
+                let anchors = theme.anchors(width, height);
+                assert!(anchors.horizon_y < height, "{kind:?}");
+                for &(x, y) in &anchors.emitters {
+                    assert!(x < width && y < height, "{kind:?} emitter");
+                }
+            }
+
+            // Below the minimum the scene is clipped, but resizing there must not panic
+            for (width, height) in [(20, 6), (10, 3), (10, 1)] {
+                let mut mask = CollisionMask::new(width, height);
+                theme.add_collision(&mut mask, width, height);
+
+                let anchors = theme.anchors(width, height);
+                assert!(anchors.horizon_y < height, "{kind:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use super::{SceneAnchors, SceneTheme, ThemeContext, render_art};
+use crate::render::TerminalRenderer;
+use crate::scene::collision::CollisionMask;
+use crate::scene::ground::GroundStyle;
+use crossterm::style::Color;
+use std::io;
+
+const MUG_X_OFFSET: u16 = 12;
+/// Rows between the window sill and the desk top
+const DESK_DROP: u16 = 4;
+
+/// The weather seen through an office window, with a desk in front of it.
+pub struct OfficeTheme;
+
+impl OfficeTheme {
+    /// Row of the desk top, pulled up onto the sill when the terminal is short
+    fn desk_y(&self, height: u16) -> u16 {
+        (self.horizon_y(height) + DESK_DROP).min(height.saturating_sub(1))
+    }
+
+    fn get_monitor(&self) -> Vec<&'static str> {
+        vec![
+            " ______________ ",
+            "|              |",
+            "|  > weathr_   |",
+            "|              |",
+            "|______________|",
+            "      _||_      ",
+        ]
+    }
+
+    fn get_mug(&self) -> Vec<&'static str> {
+        vec![" ___ ", "|   |)", "|___|"]
+    }
+}
+
+impl SceneTheme for OfficeTheme {
+    fn ground_style(&self) -> GroundStyle {
+        GroundStyle::Indoor
+    }
+
+    fn anchors(&self, width: u16, height: u16) -> SceneAnchors {
+        let horizon_y = self.horizon_y(height);
+        let mug_x = (width / 2).saturating_sub(MUG_X_OFFSET);
+        let mug_y = self.desk_y(height).saturating_sub(2);
+
+        SceneAnchors {
+            horizon_y,
+            // Steam from the coffee mug on the desk
+            emitters: vec![(mug_x + 2, mug_y.saturating_sub(1))],
+            // Along the top of the window frame
+            lights: Some((2, 0, width.saturating_sub(4))),
+            porch: None,
+        }
+    }
+
+    fn add_collision(&self, _mask: &mut CollisionMask, _width: u16, _height: u16) {
+        // Everything outside the glass is open sky; the sill is the ground row
+    }
+
+    fn render(&self, renderer: &mut TerminalRenderer, ctx: &ThemeContext) -> io::Result<()> {
+        let horizon_y = self.horizon_y(ctx.height);
+        let desk_y = self.desk_y(ctx.height);
+        let right = ctx.width.saturating_sub(1);
+
+        let frame_color = if ctx.is_day {
+            Color::White
+        } else {
+            Color::Grey
+        };
+        let desk_color = if ctx.is_day {
+            Color::Rgb {
+                r: 160,
+                g: 110,
+                b: 60,
+            }
+        } else {
+            Color::Rgb {
+                r: 80,
+                g: 55,
+                b: 30,
+            }
+        };
+
+        // Window frame with a mullion down the middle
+        for x in 0..ctx.width {
+            renderer.render_char(x, 0, '=', frame_color)?;
+        }
+        for y in 1..horizon_y {
+            renderer.render_char(0, y, '|', frame_color)?;
+            renderer.render_char(right, y, '|', frame_color)?;
+            renderer.render_char(ctx.width / 2, y, '|', frame_color)?;
+        }
+
+        // Sill and desk below the window
+        for x in 0..ctx.width {
+            renderer.render_char(x, horizon_y, '=', frame_color)?;
+            renderer.render_char(x, desk_y, '_', desk_color)?;
+        }
+        for y in desk_y + 1..ctx.height.saturating_sub(1) {
+            renderer.render_char(4, y, '|', desk_color)?;
+            renderer.render_char(right.saturating_sub(4), y, '|', desk_color)?;
+        }
+
+        let mug_x = (ctx.width / 2).saturating_sub(MUG_X_OFFSET);
+        render_art(
+            renderer,
+            mug_x,
+            desk_y.saturating_sub(2),
+            &self.get_mug(),
+            Color::White,
+        )?;
+
+        let monitor = self.get_monitor();
+        let monitor_x = ctx.width / 2 + 4;
+        let monitor_y = (desk_y + 1).saturating_sub(monitor.len() as u16);
+        let screen_color = if ctx.is_day {
+            Color::DarkGrey
+        } else {
+            Color::Cyan
+        };
+        render_art(renderer, monitor_x, monitor_y, &monitor, screen_color)
+    }
+}
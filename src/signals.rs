@@ -0,0 +1,89 @@
+use std::io;
+
+/// What a signal sent to the process asks the app to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignalAction {
+    /// SIGINT, SIGTERM or SIGQUIT: restore the terminal and exit
+    Shutdown,
+    /// SIGHUP: reload the config file
+    Reload,
+    /// SIGUSR1: fetch fresh weather right away
+    Refresh,
+}
+
+/// Process signals the app listens for while it runs.
+#[cfg(unix)]
+pub struct Signals {
+    interrupt: tokio::signal::unix::Signal,
+    terminate: tokio::signal::unix::Signal,
+    quit: tokio::signal::unix::Signal,
+    hangup: tokio::signal::unix::Signal,
+    user1: tokio::signal::unix::Signal,
+}
+
+#[cfg(unix)]
+impl Signals {
+    pub fn new() -> io::Result<Self> {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        Ok(Self {
+            interrupt: signal(SignalKind::interrupt())?,
+            terminate: signal(SignalKind::terminate())?,
+            quit: signal(SignalKind::quit())?,
+            hangup: signal(SignalKind::hangup())?,
+            user1: signal(SignalKind::user_defined1())?,
+        })
+    }
+
+    pub async fn recv(&mut self) -> SignalAction {
+        tokio::select! {
+            _ = self.interrupt.recv() => SignalAction::Shutdown,
+            _ = self.terminate.recv() => SignalAction::Shutdown,
+            _ = self.quit.recv() => SignalAction::Shutdown,
+            _ = self.hangup.recv() => SignalAction::Reload,
+            _ = self.user1.recv() => SignalAction::Refresh,
+        }
+    }
+}
+
+/// Process signals the app listens for while it runs. Only Ctrl+C is
+/// available outside Unix.
+#[cfg(not(unix))]
+pub struct Signals;
+
+#[cfg(not(unix))]
+impl Signals {
+    pub fn new() -> io::Result<Self> {
+        Ok(Self)
+    }
+
+    pub async fn recv(&mut self) -> SignalAction {
+        let _ = tokio::signal::ctrl_c().await;
+        SignalAction::Shutdown
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn send(signal: &str) {
+        let status = Command::new("kill")
+            .args([signal, &std::process::id().to_string()])
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+
+    #[tokio::test]
+    async fn test_signals_map_to_actions() {
+        let mut signals = Signals::new().unwrap();
+
+        send("-USR1");
+        assert_eq!(signals.recv().await, SignalAction::Refresh);
+
+        send("-HUP");
+        assert_eq!(signals.recv().await, SignalAction::Reload);
+    }
+}
@@ -0,0 +1,78 @@
+use rand::{Rng, RngExt};
+use std::time::Duration;
+
+/// Jittered exponential backoff between failed weather fetches.
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    base: Duration,
+    max: Duration,
+    failures: u32,
+}
+
+impl Backoff {
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self {
+            base,
+            max,
+            failures: 0,
+        }
+    }
+
+    /// Records a failure and returns how long to wait before trying again.
+    /// The wait doubles with every failure up to `max`. Up to half of it is
+    /// random so that many clients that lost the network together do not
+    /// all retry at the same moment.
+    pub fn next_delay(&mut self, rng: &mut impl Rng) -> Duration {
+        self.failures += 1;
+        let ceiling = self
+            .base
+            .saturating_mul(2_u32.saturating_pow(self.failures - 1))
+            .min(self.max);
+        let half = ceiling / 2;
+        half + half.mul_f64(rng.random::<f64>())
+    }
+
+    pub fn reset(&mut self) {
+        self.failures = 0;
+    }
+
+    /// Number of the next attempt, counting the first fetch as attempt 1.
+    pub fn attempt(&self) -> u32 {
+        self.failures + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_delay_doubles_within_jitter() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut backoff = Backoff::new(Duration::from_secs(5), Duration::from_secs(300));
+
+        for ceiling in [5, 10, 20, 40, 80] {
+            let delay = backoff.next_delay(&mut rng);
+            let ceiling = Duration::from_secs(ceiling);
+            assert!(delay >= ceiling / 2 && delay <= ceiling, "{delay:?}");
+        }
+        assert_eq!(backoff.attempt(), 6);
+    }
+
+    #[test]
+    fn test_delay_is_capped_and_resets() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let max = Duration::from_secs(300);
+        let mut backoff = Backoff::new(Duration::from_secs(5), max);
+
+        for _ in 0..64 {
+            assert!(backoff.next_delay(&mut rng) <= max);
+        }
+
+        backoff.reset();
+        assert_eq!(backoff.attempt(), 1);
+        assert!(backoff.next_delay(&mut rng) <= Duration::from_secs(5));
+    }
+}
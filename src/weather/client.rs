@@ -4,7 +4,8 @@ use crate::weather::normalizer::WeatherNormalizer;
 use crate::weather::provider::WeatherProvider;
 use crate::weather::types::{WeatherData, WeatherLocation, WeatherUnits};
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant, SystemTime};
 use tokio::sync::RwLock;
 
 #[derive(Clone)]
@@ -12,11 +13,16 @@ pub struct WeatherClient {
     provider: Arc<dyn WeatherProvider>,
     cache: Arc<RwLock<Option<CachedWeather>>>,
     cache_duration: Duration,
+    /// Set by `invalidate_cache` so the next fetch skips the on-disk cache too
+    skip_disk_cache: Arc<AtomicBool>,
 }
 
 struct CachedWeather {
     data: WeatherData,
     fetched_at: Instant,
+    /// When the provider sent the data, earlier than `fetched_at` for data
+    /// read back from the disk cache
+    observed_at: SystemTime,
 }
 
 impl WeatherClient {
@@ -25,55 +31,77 @@ impl WeatherClient {
             provider,
             cache: Arc::new(RwLock::new(None)),
             cache_duration,
+            skip_disk_cache: Arc::new(AtomicBool::new(false)),
         }
     }
 
+    #[allow(dead_code)]
     pub async fn get_current_weather(
         &self,
         location: &WeatherLocation,
         units: &WeatherUnits,
     ) -> Result<WeatherData, WeatherError> {
+        self.get_current_weather_with_time(location, units)
+            .await
+            .map(|(data, _)| data)
+    }
+
+    /// Like `get_current_weather`, also returning when the provider sent the
+    /// data. A cached answer keeps the time it was originally fetched.
+    pub async fn get_current_weather_with_time(
+        &self,
+        location: &WeatherLocation,
+        units: &WeatherUnits,
+    ) -> Result<(WeatherData, SystemTime), WeatherError> {
         {
             let cache = self.cache.read().await;
             if let Some(cached) = cache.as_ref()
                 && cached.fetched_at.elapsed() < self.cache_duration
             {
-                return Ok(cached.data.clone());
+                tracing::debug!("memory weather cache hit");
+                return Ok((cached.data.clone(), cached.observed_at));
             }
         }
 
-        if let Some(cached_data) =
-            cache::load_cached_weather(location.latitude, location.longitude).await
+        if !self.skip_disk_cache.swap(false, Ordering::Relaxed)
+            && let Some((cached_data, observed_at)) =
+                cache::load_cached_weather(location.latitude, location.longitude).await
         {
             let mut cache = self.cache.write().await;
             *cache = Some(CachedWeather {
                 data: cached_data.clone(),
                 fetched_at: Instant::now(),
+                observed_at,
             });
-            return Ok(cached_data);
+            return Ok((cached_data, observed_at));
         }
 
+        tracing::debug!("weather cache miss, fetching from the provider");
         let response = self.provider.get_current_weather(location, units).await?;
 
         let data = WeatherNormalizer::normalize(response);
+        let observed_at = SystemTime::now();
 
         {
             let mut cache = self.cache.write().await;
             *cache = Some(CachedWeather {
                 data: data.clone(),
                 fetched_at: Instant::now(),
+                observed_at,
             });
         }
 
         cache::save_weather_cache(&data, location.latitude, location.longitude);
 
-        Ok(data)
+        Ok((data, observed_at))
     }
 
-    #[allow(dead_code)]
+    /// Forces the next call to fetch from the provider.
     pub async fn invalidate_cache(&self) {
         let mut cache = self.cache.write().await;
         *cache = None;
+        self.skip_disk_cache.store(true, Ordering::Relaxed);
+        tracing::debug!("weather cache invalidated");
     }
 }
 
@@ -92,5 +120,6 @@ mod tests {
 
         let cache = client.cache.read().await;
         assert!(cache.is_none());
+        assert!(client.skip_disk_cache.load(Ordering::Relaxed));
     }
 }
@@ -1,3 +1,4 @@
+pub mod backoff;
 pub mod client;
 pub mod normalizer;
 pub mod open_meteo;
@@ -9,6 +10,6 @@ pub use client::WeatherClient;
 pub use open_meteo::OpenMeteoProvider;
 pub use types::{
     FogIntensity, RainIntensity, SnowIntensity, WeatherCondition, WeatherConditions, WeatherData,
-    WeatherLocation, WeatherUnits,
+    WeatherLocation, WeatherUnits, WindConditions,
 };
-pub use units::{format_precipitation, format_temperature, format_wind_speed};
+pub use units::{beaufort_scale, format_precipitation, format_temperature, format_wind_speed};
@@ -15,6 +15,7 @@ impl WeatherNormalizer {
             precipitation: response.precipitation,
             wind_speed: response.wind_speed,
             wind_direction: response.wind_direction,
+            wind_gusts: response.wind_gusts,
             cloud_cover: response.cloud_cover,
             pressure: response.pressure,
             visibility: response.visibility,
@@ -104,6 +105,7 @@ mod tests {
             precipitation: 2.5,
             wind_speed: 15.0,
             wind_direction: 180.0,
+            wind_gusts: Some(30.0),
             cloud_cover: 85.0,
             pressure: 1013.0,
             visibility: Some(10000.0),
@@ -1,4 +1,6 @@
+use crate::config::HttpConfig;
 use crate::error::{NetworkError, WeatherError};
+use crate::http;
 use crate::weather::provider::{WeatherProvider, WeatherProviderResponse};
 use crate::weather::types::{
     PrecipitationUnit, TemperatureUnit, WeatherLocation, WeatherUnits, WindSpeedUnit,
@@ -6,13 +8,14 @@ use crate::weather::types::{
 use crate::weather::units::{normalize_precipitation, normalize_temperature, normalize_wind_speed};
 use async_trait::async_trait;
 use serde::Deserialize;
-use std::time::Duration;
+use std::time::Instant;
 
 const OPEN_METEO_BASE_URL: &str = "https://api.open-meteo.com/v1/forecast";
 
 pub struct OpenMeteoProvider {
     client: reqwest::Client,
     base_url: String,
+    timeout_secs: u64,
 }
 
 #[derive(Debug, Deserialize)]
@@ -34,25 +37,34 @@ struct CurrentWeather {
     wind_speed_10m: f64,
     wind_direction_10m: f64,
     #[serde(default)]
+    wind_gusts_10m: Option<f64>,
+    #[serde(default)]
     visibility: Option<f64>,
 }
 
 impl OpenMeteoProvider {
     pub fn new() -> Self {
-        let client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(30))
-            .connect_timeout(Duration::from_secs(10))
-            .build()
-            .unwrap_or_else(|e| {
-                eprintln!("Warning: Failed to create custom HTTP client: {}", e);
-                eprintln!("Using default client with standard timeout settings.");
-                reqwest::Client::new()
-            });
-
-        Self {
-            client,
+        let config = HttpConfig::default();
+        Self::with_http_config(&config).unwrap_or_else(|e| {
+            tracing::warn!(
+                error = %e,
+                "failed to create custom HTTP client, using the default timeouts"
+            );
+            Self {
+                client: reqwest::Client::new(),
+                base_url: OPEN_METEO_BASE_URL.to_string(),
+                timeout_secs: config.timeout_secs,
+            }
+        })
+    }
+
+    /// A provider whose requests follow the `[http]` config.
+    pub fn with_http_config(config: &HttpConfig) -> Result<Self, NetworkError> {
+        Ok(Self {
+            client: http::build_client(config)?,
             base_url: OPEN_METEO_BASE_URL.to_string(),
-        }
+            timeout_secs: config.timeout_secs,
+        })
     }
 
     fn temperature_unit_param(unit: &TemperatureUnit) -> &'static str {
@@ -80,7 +92,7 @@ impl OpenMeteoProvider {
 
     fn build_url(&self, location: &WeatherLocation, units: &WeatherUnits) -> String {
         format!(
-            "{}?latitude={}&longitude={}&current=temperature_2m,relative_humidity_2m,apparent_temperature,is_day,precipitation,weather_code,cloud_cover,surface_pressure,wind_speed_10m,wind_direction_10m,visibility&temperature_unit={}&wind_speed_unit={}&precipitation_unit={}&timezone=auto",
+            "{}?latitude={}&longitude={}&current=temperature_2m,relative_humidity_2m,apparent_temperature,is_day,precipitation,weather_code,cloud_cover,surface_pressure,wind_speed_10m,wind_direction_10m,wind_gusts_10m,visibility&temperature_unit={}&wind_speed_unit={}&precipitation_unit={}&timezone=auto",
             self.base_url,
             location.latitude,
             location.longitude,
@@ -105,17 +117,28 @@ impl WeatherProvider for OpenMeteoProvider {
         units: &WeatherUnits,
     ) -> Result<WeatherProviderResponse, WeatherError> {
         let url = self.build_url(location, units);
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| WeatherError::Network(NetworkError::from_reqwest(e, &url, 30)))?;
-
-        let data: OpenMeteoResponse = response
-            .json()
-            .await
-            .map_err(|e| WeatherError::Network(NetworkError::from_reqwest(e, &url, 30)))?;
+        let started = Instant::now();
+        let response = self.client.get(&url).send().await.map_err(|e| {
+            let error = NetworkError::from_reqwest(e, &url, self.timeout_secs);
+            tracing::warn!(
+                url = %url,
+                latency_ms = started.elapsed().as_millis() as u64,
+                kind = error.kind(),
+                "weather request failed"
+            );
+            WeatherError::Network(error)
+        })?;
+        tracing::info!(
+            url = %url,
+            status = response.status().as_u16(),
+            latency_ms = started.elapsed().as_millis() as u64,
+            "weather request"
+        );
+        let response = NetworkError::check_response(response, &url)?;
+
+        let data: OpenMeteoResponse = response.json().await.map_err(|e| {
+            WeatherError::Network(NetworkError::from_reqwest(e, &url, self.timeout_secs))
+        })?;
 
         let moon_phase = Some(0.5);
 
@@ -130,6 +153,10 @@ impl WeatherProvider for OpenMeteoProvider {
             precipitation: normalize_precipitation(data.current.precipitation, units.precipitation),
             wind_speed: normalize_wind_speed(data.current.wind_speed_10m, units.wind_speed),
             wind_direction: data.current.wind_direction_10m,
+            wind_gusts: data
+                .current
+                .wind_gusts_10m
+                .map(|gusts| normalize_wind_speed(gusts, units.wind_speed)),
             cloud_cover: data.current.cloud_cover,
             pressure: data.current.surface_pressure,
             visibility: data.current.visibility,
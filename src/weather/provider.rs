@@ -12,6 +12,8 @@ pub struct WeatherProviderResponse {
     pub precipitation: f64,
     pub wind_speed: f64,
     pub wind_direction: f64,
+    #[serde(default)]
+    pub wind_gusts: Option<f64>,
     pub cloud_cover: f64,
     pub pressure: f64,
     pub visibility: Option<f64>,
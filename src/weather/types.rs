@@ -158,6 +158,8 @@ pub struct WeatherData {
     pub precipitation: f64,
     pub wind_speed: f64,
     pub wind_direction: f64,
+    #[serde(default)]
+    pub wind_gusts: Option<f64>,
     pub cloud_cover: f64,
     pub pressure: f64,
     pub visibility: Option<f64>,
@@ -166,7 +168,7 @@ pub struct WeatherData {
     pub timestamp: String,
 }
 
-#[derive(Debug, Clone, Copy, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize)]
 #[serde(default)]
 pub struct WeatherUnits {
     pub temperature: TemperatureUnit,
@@ -198,7 +200,7 @@ impl Default for WeatherUnits {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[allow(dead_code)]
 pub struct WeatherLocation {
     pub latitude: f64,
@@ -206,6 +208,14 @@ pub struct WeatherLocation {
     pub elevation: Option<f64>,
 }
 
+/// Mean wind at the location. Speed is in m/s, direction is where the wind
+/// blows from in degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct WindConditions {
+    pub speed: f64,
+    pub direction: f64,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct WeatherConditions {
     pub is_raining: bool,
@@ -228,3 +238,10 @@ impl Default for WeatherConditions {
         }
     }
 }
+
+impl WeatherConditions {
+    /// Whether anything is falling from the sky
+    pub fn is_precipitating(&self) -> bool {
+        self.is_raining || self.is_thunderstorm || self.is_snowing
+    }
+}
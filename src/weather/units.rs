@@ -85,3 +85,25 @@ pub fn normalize_precipitation(value: f64, unit: PrecipitationUnit) -> f64 {
         PrecipitationUnit::Inch => inch_to_mm(value),
     }
 }
+
+/// Beaufort force (0-12) for a wind speed in m/s.
+pub fn beaufort_scale(ms: f64) -> u8 {
+    const LIMITS: [f64; 12] = [
+        0.5, 1.6, 3.4, 5.5, 8.0, 10.8, 13.9, 17.2, 20.8, 24.5, 28.5, 32.7,
+    ];
+    LIMITS.iter().take_while(|&&limit| ms >= limit).count() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_beaufort_scale() {
+        assert_eq!(beaufort_scale(0.0), 0);
+        assert_eq!(beaufort_scale(1.0), 1);
+        assert_eq!(beaufort_scale(kmh_to_ms(18.0)), 3);
+        assert_eq!(beaufort_scale(kmh_to_ms(45.0)), 6);
+        assert_eq!(beaufort_scale(40.0), 12);
+    }
+}
@@ -1,6 +1,7 @@
 use std::fs;
 use std::io::Write;
 use weathr::config::Config;
+use weathr::scene::themes::custom::CustomTheme;
 
 #[test]
 fn test_config_integration_load_valid_file() {
@@ -115,3 +116,25 @@ fn test_config_integration_extra_whitespace() {
 
     fs::remove_file(test_config_path).ok();
 }
+
+#[test]
+fn test_config_integration_invalid_scene_file() {
+    let temp_dir = std::env::temp_dir();
+    let scene_path = temp_dir.join("weathr_invalid_scene.toml");
+
+    let mut file = fs::File::create(&scene_path).unwrap();
+    writeln!(file, "[[layers]]").unwrap();
+    writeln!(file, "art = \"[]\"").unwrap();
+    writeln!(file, "color = \"not-a-color\"").unwrap();
+    drop(file);
+
+    let error = match CustomTheme::load_from_path(&scene_path) {
+        Ok(_) => panic!("Scene with an unknown color should be rejected"),
+        Err(error) => error,
+    };
+
+    assert_eq!(error.kind(), "InvalidScene");
+    assert!(error.to_string().contains("line 3"));
+
+    fs::remove_file(scene_path).ok();
+}
@@ -1,4 +1,8 @@
-use weathr::error::{ConfigError, GeolocationError, NetworkError, TerminalError};
+use std::time::Duration;
+use weathr::error::{
+    ConfigError, GeolocationError, MAX_RETRY_AFTER, NetworkError, TerminalError, WeatherError,
+    parse_retry_after,
+};
 
 #[test]
 fn test_config_error_kind() {
@@ -40,6 +44,24 @@ fn test_network_error_is_retryable() {
     assert!(connection_refused.is_retryable());
 }
 
+#[test]
+fn test_network_error_kind() {
+    let error = NetworkError::Timeout {
+        url: "https://example.com".to_string(),
+        timeout_secs: 10,
+    };
+    assert_eq!(error.kind(), "Timeout");
+
+    let error = WeatherError::from(NetworkError::ConnectionRefused {
+        url: "https://example.com".to_string(),
+    });
+    assert_eq!(error.kind(), "ConnectionRefused");
+    assert!(error.user_friendly_message().contains("Cannot connect"));
+
+    let error = WeatherError::from(ConfigError::NoConfigDir);
+    assert_eq!(error.kind(), "NoConfigDir");
+}
+
 #[test]
 fn test_network_error_user_friendly_message() {
     let timeout_error = NetworkError::Timeout {
@@ -100,6 +122,14 @@ fn test_geolocation_error_user_friendly_message() {
     let msg = retries_exhausted.user_friendly_message();
     assert!(msg.contains("3 attempts"));
     assert!(msg.contains("configured/default"));
+
+    let msg = GeolocationError::NetworkDisabled.user_friendly_message();
+    assert!(msg.contains("strict privacy mode"));
+    assert!(msg.contains("configured/default"));
+
+    let msg = GeolocationError::CityNotFound("Atlantis".to_string()).user_friendly_message();
+    assert!(msg.contains("'Atlantis'"));
+    assert!(msg.contains("configured/default"));
 }
 
 #[test]
@@ -122,3 +152,72 @@ fn test_geolocation_error_all_network_variants() {
     assert!(!msg.contains("Network error:"));
     assert!(!msg.contains("Cannot auto-detect location:"));
 }
+
+#[test]
+fn test_network_error_from_status() {
+    let url = "https://api.example.com";
+
+    let error = NetworkError::from_status(url, 429, Some(Duration::from_secs(20))).unwrap();
+    assert_eq!(error.kind(), "RateLimited");
+    assert!(error.is_retryable());
+    assert_eq!(error.retry_after(), Some(Duration::from_secs(20)));
+    assert!(error.user_friendly_message().contains("in 20s"));
+
+    let error = NetworkError::from_status(url, 403, None).unwrap();
+    assert_eq!(error.kind(), "Unauthorized");
+    assert!(!error.is_retryable());
+    assert!(error.user_friendly_message().contains("API key"));
+
+    let error = NetworkError::from_status(url, 503, None).unwrap();
+    assert_eq!(error.kind(), "ServerError");
+    assert!(error.is_retryable());
+    assert_eq!(error.retry_after(), None);
+
+    assert!(NetworkError::from_status(url, 404, None).is_none());
+}
+
+#[test]
+fn test_parse_retry_after() {
+    let now = chrono::DateTime::parse_from_rfc2822("Wed, 21 Oct 2015 07:28:00 GMT")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+
+    assert_eq!(
+        parse_retry_after("120", now),
+        Some(Duration::from_secs(120))
+    );
+    assert_eq!(
+        parse_retry_after("Wed, 21 Oct 2015 07:29:30 GMT", now),
+        Some(Duration::from_secs(90))
+    );
+    assert_eq!(
+        parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now),
+        Some(Duration::ZERO)
+    );
+    assert_eq!(parse_retry_after("soon", now), None);
+}
+
+#[test]
+fn test_parse_retry_after_caps_huge_waits() {
+    let now = chrono::Utc::now();
+
+    assert_eq!(
+        parse_retry_after("18446744073709551615", now),
+        Some(MAX_RETRY_AFTER)
+    );
+    assert_eq!(
+        parse_retry_after("Fri, 31 Dec 9999 23:59:59 GMT", now),
+        Some(MAX_RETRY_AFTER)
+    );
+}
+
+#[test]
+fn test_geolocation_error_rate_limited_message() {
+    let geo_error = GeolocationError::Unreachable(NetworkError::RateLimited {
+        url: "https://ipinfo.io".to_string(),
+        retry_after: None,
+    });
+    let msg = geo_error.user_friendly_message();
+    assert!(msg.contains("too many requests"));
+    assert!(msg.contains("configured/default"));
+}
@@ -0,0 +1,40 @@
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+use weathr::config::{Config, Privacy};
+use weathr::error::GeolocationError;
+use weathr::geolocation::detect_location;
+
+#[tokio::test]
+async fn test_strict_privacy_uses_cached_location() {
+    let cache_home = std::env::temp_dir().join(format!("weathr_geo_cache_{}", std::process::id()));
+    let cache_dir = cache_home.join("weathr");
+    fs::create_dir_all(&cache_dir).unwrap();
+    // The only test in this binary, so nothing else reads the variable meanwhile
+    unsafe { std::env::set_var("XDG_CACHE_HOME", &cache_home) };
+
+    let config = Config {
+        privacy: Privacy::Strict,
+        ..Config::default()
+    };
+
+    let error = detect_location(&config).await.unwrap_err();
+    assert!(matches!(error, GeolocationError::NetworkDisabled));
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    fs::write(
+        cache_dir.join("location.json"),
+        format!(
+            r#"{{"location": {{"latitude": 59.91, "longitude": 10.75, "city": "Oslo"}}, "cached_at": {now}}}"#
+        ),
+    )
+    .unwrap();
+
+    let location = detect_location(&config).await.unwrap();
+    assert_eq!(location.latitude, 59.91);
+    assert_eq!(location.city.as_deref(), Some("Oslo"));
+
+    fs::remove_dir_all(cache_home).ok();
+}
@@ -44,6 +44,7 @@ fn test_weather_normalizer_integration_all_wmo_codes() {
             precipitation: 0.0,
             wind_speed: 10.0,
             wind_direction: 180.0,
+            wind_gusts: None,
             cloud_cover: 50.0,
             pressure: 1013.0,
             visibility: Some(10000.0),
@@ -71,6 +72,7 @@ fn test_weather_normalizer_integration_day_night() {
         precipitation: 0.0,
         wind_speed: 10.0,
         wind_direction: 180.0,
+        wind_gusts: None,
         cloud_cover: 0.0,
         pressure: 1013.0,
         visibility: Some(10000.0),
@@ -87,6 +89,7 @@ fn test_weather_normalizer_integration_day_night() {
         precipitation: 0.0,
         wind_speed: 5.0,
         wind_direction: 180.0,
+        wind_gusts: None,
         cloud_cover: 0.0,
         pressure: 1013.0,
         visibility: Some(10000.0),
@@ -112,6 +115,7 @@ fn test_weather_normalizer_integration_clear_conditions() {
         precipitation: 0.0,
         wind_speed: 5.0,
         wind_direction: 90.0,
+        wind_gusts: None,
         cloud_cover: 10.0,
         pressure: 1015.0,
         visibility: Some(15000.0),
@@ -140,6 +144,7 @@ fn test_weather_normalizer_integration_rainy_conditions() {
         precipitation: 5.2,
         wind_speed: 12.0,
         wind_direction: 270.0,
+        wind_gusts: None,
         cloud_cover: 95.0,
         pressure: 1005.0,
         visibility: Some(3000.0),
@@ -165,6 +170,7 @@ fn test_weather_normalizer_integration_snowy_conditions() {
         precipitation: 3.5,
         wind_speed: 8.0,
         wind_direction: 0.0,
+        wind_gusts: None,
         cloud_cover: 100.0,
         pressure: 1010.0,
         visibility: Some(1000.0),